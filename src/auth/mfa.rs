@@ -0,0 +1,377 @@
+//! Implementation of the multi-factor authentication methods of the Authentication API.
+
+use derive_builder::Builder;
+use serde::Serialize;
+use serde_with::{formats::SpaceSeparator, serde_as, StringWithSeparator};
+
+use super::GET_TOKEN_ENDPOINT;
+use crate::{http::path_segment, models, AuthenticationApi, Result};
+
+const MFA_CHALLENGE_ENDPOINT: &str = "/mfa/challenge";
+const MFA_ASSOCIATE_ENDPOINT: &str = "/mfa/associate";
+const MFA_AUTHENTICATORS_ENDPOINT: &str = "/mfa/authenticators";
+const GRANT_TYPE_MFA_OTP: &str = "http://auth0.com/oauth/grant-type/mfa-otp";
+const GRANT_TYPE_MFA_OOB: &str = "http://auth0.com/oauth/grant-type/mfa-oob";
+const GRANT_TYPE_MFA_RECOVERY_CODE: &str = "http://auth0.com/oauth/grant-type/mfa-recovery-code";
+
+/// This struct provides an implementation of the multi-factor authentication methods of the
+/// Authentication API.
+pub struct Mfa {
+    pub(crate) api: AuthenticationApi,
+    pub(crate) mfa_token: String,
+}
+
+impl Mfa {
+    /// Request a challenge for an enrolled authenticator, implementation of [challenge request].
+    ///
+    /// [challenge request]: https://auth0.com/docs/api/authentication#challenge-request
+    pub fn challenge(&self) -> MfaChallengeBuilder {
+        let mut builder = MfaChallengeBuilder::default();
+        builder
+            .api(self.api.clone())
+            .client_id(self.api.client_id().to_string())
+            .client_secret(self.api.client_secret().map(Into::into))
+            .mfa_token(self.mfa_token.clone());
+        builder
+    }
+
+    /// Enroll a new authenticator, implementation of [add an authenticator].
+    ///
+    /// [add an authenticator]: https://auth0.com/docs/api/authentication#add-an-authenticator
+    pub fn associate(&self) -> MfaAssociateBuilder {
+        let mut builder = MfaAssociateBuilder::default();
+        builder
+            .api(self.api.clone())
+            .client_id(self.api.client_id().to_string())
+            .client_secret(self.api.client_secret().map(Into::into))
+            .mfa_token(self.mfa_token.clone());
+        builder
+    }
+
+    /// List the authenticators of the user, implementation of [list authenticators].
+    ///
+    /// [list authenticators]: https://auth0.com/docs/api/authentication#list-authenticators
+    pub async fn authenticators(&self) -> Result<Vec<models::MfaAuthenticator>> {
+        self.api
+            .http_get_with_token(MFA_AUTHENTICATORS_ENDPOINT, &self.mfa_token)
+            .await
+    }
+
+    /// Delete an authenticator of the user, implementation of [delete an authenticator].
+    ///
+    /// [delete an authenticator]: https://auth0.com/docs/api/authentication#delete-an-authenticator
+    pub async fn delete_authenticator(&self, authenticator_id: &str) -> Result<()> {
+        let endpoint = format!(
+            "{MFA_AUTHENTICATORS_ENDPOINT}/{}",
+            path_segment(authenticator_id)
+        );
+        self.api
+            .http_delete_with_token(&endpoint, &self.mfa_token)
+            .await
+    }
+
+    /// Get an access token by using a one-time password, implementation of [verify with OTP].
+    ///
+    /// [verify with OTP]: https://auth0.com/docs/api/authentication#verify-with-one-time-password-otp-
+    pub fn verify_otp<T: Into<String>>(&self, otp: T) -> MfaOtpLoginBuilder {
+        let mut builder = MfaOtpLoginBuilder::default();
+        builder
+            .api(self.api.clone())
+            .grant_type(GRANT_TYPE_MFA_OTP)
+            .client_id(self.api.client_id().to_string())
+            .client_secret(self.api.client_secret().map(Into::into))
+            .mfa_token(self.mfa_token.clone())
+            .otp(otp);
+        builder
+    }
+
+    /// Get an access token by using the out-of-band code of a challenge, implementation of
+    /// [verify with OOB].
+    ///
+    /// [verify with OOB]: https://auth0.com/docs/api/authentication#verify-with-out-of-band-oob-
+    pub fn verify_oob<T: Into<String>>(&self, oob_code: T) -> MfaOobLoginBuilder {
+        let mut builder = MfaOobLoginBuilder::default();
+        builder
+            .api(self.api.clone())
+            .grant_type(GRANT_TYPE_MFA_OOB)
+            .client_id(self.api.client_id().to_string())
+            .client_secret(self.api.client_secret().map(Into::into))
+            .mfa_token(self.mfa_token.clone())
+            .oob_code(oob_code);
+        builder
+    }
+
+    /// Get an access token by using a recovery code, implementation of [verify with recovery
+    /// code].
+    ///
+    /// [verify with recovery code]: https://auth0.com/docs/api/authentication#verify-with-recovery-code
+    pub fn verify_recovery_code<T: Into<String>>(
+        &self,
+        recovery_code: T,
+    ) -> MfaRecoveryCodeLoginBuilder {
+        let mut builder = MfaRecoveryCodeLoginBuilder::default();
+        builder
+            .api(self.api.clone())
+            .grant_type(GRANT_TYPE_MFA_RECOVERY_CODE)
+            .client_id(self.api.client_id().to_string())
+            .client_secret(self.api.client_secret().map(Into::into))
+            .mfa_token(self.mfa_token.clone())
+            .recovery_code(recovery_code);
+        builder
+    }
+}
+
+/// Request a challenge for an authenticator.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct MfaChallenge {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// The token received from the `mfa_required` error.
+    #[builder(private)]
+    mfa_token: String,
+    /// The challenge types supported by the application, such as `otp` or `oob`.
+    #[serde_as(as = "StringWithSeparator::<SpaceSeparator, String>")]
+    #[builder(setter(custom), default)]
+    challenge_type: Vec<String>,
+    /// The ID of the authenticator to challenge.
+    #[builder(setter(strip_option, into), default)]
+    authenticator_id: Option<String>,
+}
+
+impl MfaChallengeBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::MfaChallenge> {
+        let request = self.build()?;
+        request
+            .api
+            .http_post(MFA_CHALLENGE_ENDPOINT, &request)
+            .await
+    }
+
+    /// Append one element to the list of challenge types.
+    pub fn challenge_type<T: Into<String>>(&mut self, challenge_type: T) -> &mut Self {
+        self.challenge_type
+            .get_or_insert_with(Vec::new)
+            .push(challenge_type.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of challenge types.
+    pub fn challenge_types<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.challenge_type
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Enroll a new authenticator.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct MfaAssociate {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// The token received from the `mfa_required` error, sent as bearer token.
+    #[builder(private)]
+    #[serde(skip)]
+    mfa_token: String,
+    /// The types of authenticator to enroll, such as `otp` or `oob`.
+    #[builder(setter(custom), default)]
+    authenticator_types: Vec<String>,
+    /// The channels of an `oob` authenticator, such as `sms`, `voice` or `auth0`.
+    #[builder(setter(custom), default)]
+    oob_channels: Vec<String>,
+    /// The phone number to use for `sms` or `voice` channels.
+    #[builder(setter(strip_option, into), default)]
+    phone_number: Option<String>,
+    /// The email address to use for the `email` channel.
+    #[builder(setter(strip_option, into), default)]
+    email: Option<String>,
+}
+
+impl MfaAssociateBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::MfaAssociation> {
+        let request = self.build()?;
+        request
+            .api
+            .http_post_with_token(MFA_ASSOCIATE_ENDPOINT, &request.mfa_token, &request)
+            .await
+    }
+
+    /// Append one element to the list of authenticator types.
+    pub fn authenticator_type<T: Into<String>>(&mut self, authenticator_type: T) -> &mut Self {
+        self.authenticator_types
+            .get_or_insert_with(Vec::new)
+            .push(authenticator_type.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of authenticator types.
+    pub fn authenticator_types<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.authenticator_types
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    /// Append one element to the list of out-of-band channels.
+    pub fn oob_channel<T: Into<String>>(&mut self, oob_channel: T) -> &mut Self {
+        self.oob_channels
+            .get_or_insert_with(Vec::new)
+            .push(oob_channel.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of out-of-band channels.
+    pub fn oob_channels<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.oob_channels
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Get an access token by using a one-time password.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct MfaOtpLogin {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Denotes the flow you are using.
+    #[builder(private)]
+    grant_type: &'static str,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// The token received from the `mfa_required` error.
+    #[builder(private)]
+    mfa_token: String,
+    /// The one-time password generated by the authenticator.
+    #[builder(private, setter(into))]
+    otp: String,
+}
+
+impl MfaOtpLoginBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::AccessToken> {
+        let request = self.build()?;
+        request.api.http_post(GET_TOKEN_ENDPOINT, &request).await
+    }
+}
+
+/// Get an access token by using an out-of-band code.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct MfaOobLogin {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Denotes the flow you are using.
+    #[builder(private)]
+    grant_type: &'static str,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// The token received from the `mfa_required` error.
+    #[builder(private)]
+    mfa_token: String,
+    /// The out-of-band code received from the challenge request.
+    #[builder(private, setter(into))]
+    oob_code: String,
+    /// The code the user received, required when the challenge binding method is `prompt`.
+    #[builder(setter(strip_option, into), default)]
+    binding_code: Option<String>,
+}
+
+impl MfaOobLoginBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::AccessToken> {
+        let request = self.build()?;
+        request.api.http_post(GET_TOKEN_ENDPOINT, &request).await
+    }
+}
+
+/// Get an access token by using a recovery code.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct MfaRecoveryCodeLogin {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Denotes the flow you are using.
+    #[builder(private)]
+    grant_type: &'static str,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// The token received from the `mfa_required` error.
+    #[builder(private)]
+    mfa_token: String,
+    /// The recovery code provided to the user when enrolling.
+    #[builder(private, setter(into))]
+    recovery_code: String,
+}
+
+impl MfaRecoveryCodeLoginBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::AccessToken> {
+        let request = self.build()?;
+        request.api.http_post(GET_TOKEN_ENDPOINT, &request).await
+    }
+}
@@ -0,0 +1,1789 @@
+//! Implementation of the Authentication API.
+
+pub mod mfa;
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use derive_builder::Builder;
+use rand::RngCore;
+use reqwest::{Method, Url};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value as JsonValue};
+use serde_with::{formats::SpaceSeparator, serde_as, StringWithSeparator};
+use sha2::{Digest, Sha256};
+use tokio::sync::OnceCell;
+
+use self::mfa::Mfa;
+use crate::{
+    error::ErrorCode,
+    http::{self, HttpClient},
+    jwt::{
+        Algorithm, ClientAssertionSigner, JwksCache, VerifyAccessTokenBuilder,
+        VerifyIdTokenBuilder, CLIENT_ASSERTION_TYPE, JWKS_ENDPOINT,
+    },
+    models,
+    retry::{RateLimit, RetryPolicy},
+    Error, Result,
+};
+
+const DISCOVERY_ENDPOINT: &str = "/.well-known/openid-configuration";
+const AUTHORIZE_ENDPOINT: &str = "/authorize";
+const LOGOUT_ENDPOINT: &str = "/v2/logout";
+const OIDC_LOGOUT_ENDPOINT: &str = "/oidc/logout";
+const SIGNUP_ENDPOINT: &str = "/dbconnections/signup";
+const CHANGE_PASSWORD_ENDPOINT: &str = "/dbconnections/change_password";
+const GET_DEVICE_CODE_ENDPOINT: &str = "/oauth/device/code";
+const GET_TOKEN_ENDPOINT: &str = "/oauth/token";
+const PASSWORDLESS_START_ENDPOINT: &str = "/passwordless/start";
+const PAR_ENDPOINT: &str = "/oauth/par";
+const BACKCHANNEL_AUTHORIZE_ENDPOINT: &str = "/bc-authorize";
+const REVOKE_TOKEN_ENDPOINT: &str = "/oauth/revoke";
+const USER_INFO_ENDPOINT: &str = "/userinfo";
+const GRANT_TYPE_CLIENT_CREDENTIALS: &str = "client_credentials";
+const GRANT_TYPE_RESOURCE_OWNED_PASSWORD: &str = "http://auth0.com/oauth/grant-type/password-realm";
+const GRANT_TYPE_AUTHORIZATION_CODE: &str = "authorization_code";
+const GRANT_TYPE_REFRESH_TOKEN: &str = "refresh_token";
+const GRANT_TYPE_DEVICE_CODE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+const GRANT_TYPE_TOKEN_EXCHANGE: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+const GRANT_TYPE_CIBA: &str = "urn:openid:params:grant-type:ciba";
+const GRANT_TYPE_PASSWORDLESS_OTP: &str = "http://auth0.com/oauth/grant-type/passwordless/otp";
+
+/// Endpoints requiring the application to authenticate with its credentials.
+const CLIENT_AUTHENTICATION_ENDPOINTS: &[&str] = &[
+    GET_TOKEN_ENDPOINT,
+    REVOKE_TOKEN_ENDPOINT,
+    PAR_ENDPOINT,
+    BACKCHANNEL_AUTHORIZE_ENDPOINT,
+];
+
+/// Delay added to the polling interval every time Auth0 answers with `slow_down`.
+const SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+
+/// Implementation of the authentication API.
+#[derive(Clone)]
+pub struct AuthenticationApi(Arc<Inner>);
+
+struct Inner {
+    /// The tenant's domain.
+    domain: Url,
+    /// Application's Client ID.
+    client_id: String,
+    /// Application's Client Secret.
+    client_secret: Option<String>,
+    /// HTTP client.
+    client: HttpClient,
+    /// Cache of the keys used to sign the tokens.
+    jwks: JwksCache,
+    /// The discovery document, once fetched.
+    discovery: OnceCell<models::OpenIdConfiguration>,
+    /// Whether to use the endpoints of the discovery document.
+    use_discovery: AtomicBool,
+    /// Signer of the client assertions, when the application authenticates with a private key.
+    client_assertion: Option<ClientAssertionSigner>,
+}
+
+impl Inner {
+    /// Create the state of a client without credentials.
+    fn new(domain: &str, client_id: String) -> Result<Self> {
+        Ok(Self {
+            domain: Url::parse(domain)?,
+            client_id,
+            client_secret: None,
+            client: HttpClient::default(),
+            jwks: JwksCache::default(),
+            discovery: OnceCell::new(),
+            use_discovery: AtomicBool::new(false),
+            client_assertion: None,
+        })
+    }
+}
+
+impl std::fmt::Debug for AuthenticationApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthenticationApi")
+            .field("domain", &self.0.domain)
+            .field("client_id", &self.0.client_id)
+            .finish()
+    }
+}
+
+impl AuthenticationApi {
+    /// Create a new istance of the authentication API.
+    pub fn new<T: Into<String>>(domain: &str, client_id: T) -> Result<Self> {
+        let inner = Inner::new(domain, client_id.into())?;
+        Ok(Self(Arc::new(inner)))
+    }
+
+    /// Create a new istance of the authentication API.
+    pub fn with_client_secret<T, U>(domain: &str, client_id: T, client_secret: U) -> Result<Self>
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let inner = Inner {
+            client_secret: Some(client_secret.into()),
+            ..Inner::new(domain, client_id.into())?
+        };
+        Ok(Self(Arc::new(inner)))
+    }
+
+    /// Create a new istance of the authentication API, authenticating the application with
+    /// [Private Key JWT] instead of a client secret.
+    ///
+    /// The calls requiring client authentication send a `client_assertion` signed with the given
+    /// PEM encoded RSA private key, whose public key must be registered in the application's
+    /// credentials with the given key ID. Supported algorithms are `RS256`, `RS384` and `PS256`.
+    ///
+    /// [Private Key JWT]: https://auth0.com/docs/get-started/authentication-and-authorization-flow/authenticate-with-private-key-jwt
+    pub fn with_private_key_jwt<T, K>(
+        domain: &str,
+        client_id: T,
+        pem_key: &[u8],
+        kid: K,
+        alg: Algorithm,
+    ) -> Result<Self>
+    where
+        T: Into<String>,
+        K: Into<String>,
+    {
+        let inner = Inner {
+            client_assertion: Some(ClientAssertionSigner::new(pem_key, kid.into(), alg)?),
+            ..Inner::new(domain, client_id.into())?
+        };
+        Ok(Self(Arc::new(inner)))
+    }
+
+    /// Set the policy used to retry failed requests.
+    ///
+    /// The policy is shared with all the clones of this instance.
+    pub fn with_retry_policy(self, policy: RetryPolicy) -> Self {
+        self.0.client.set_retry_policy(policy);
+        self
+    }
+
+    /// Returns the policy used to retry failed requests.
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.0.client.retry_policy()
+    }
+
+    /// Returns the last rate limit state reported by Auth0, if any.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.0.client.rate_limit()
+    }
+
+    /// Use the endpoints advertised by the discovery document of the tenant instead of the
+    /// default paths, which is useful with custom domains and other OpenID Connect providers.
+    ///
+    /// The issuer expected when verifying tokens is also taken from the document.
+    ///
+    /// The document is fetched by the first request. The URL builders only use it once it has
+    /// been fetched, call [`AuthenticationApi::discover`] beforehand to make sure it is.
+    ///
+    /// The setting is shared with all the clones of this instance.
+    pub fn with_discovery(self) -> Self {
+        self.0.use_discovery.store(true, Ordering::Relaxed);
+        self
+    }
+
+    /// Fetch the OpenID Connect discovery document of the tenant, implementation of
+    /// [`/.well-known/openid-configuration`].
+    ///
+    /// The document is cached, subsequent calls return it without any request.
+    ///
+    /// [`/.well-known/openid-configuration`]: https://auth0.com/docs/get-started/applications/configure-applications-with-oidc-discovery
+    pub async fn discover(&self) -> Result<&models::OpenIdConfiguration> {
+        self.0
+            .discovery
+            .get_or_try_init(|| async {
+                let request = self
+                    .0
+                    .client
+                    .request(Method::GET, self.0.domain.join(DISCOVERY_ENDPOINT)?);
+                let response = self.0.client.send(request).await?;
+                http::json_response(response).await
+            })
+            .await
+    }
+
+    /// Returns the URL of the given endpoint, fetching the discovery document if it must be used.
+    async fn url(&self, endpoint: &str) -> Result<Url> {
+        if self.0.use_discovery.load(Ordering::Relaxed) {
+            self.discover().await?;
+        }
+        self.cached_url(endpoint)
+    }
+
+    /// Returns the URL of the given endpoint, using the discovery document only if it has already
+    /// been fetched.
+    fn cached_url(&self, endpoint: &str) -> Result<Url> {
+        let discovered = self
+            .0
+            .discovery
+            .get()
+            .filter(|_| self.0.use_discovery.load(Ordering::Relaxed))
+            .and_then(|config| match endpoint {
+                AUTHORIZE_ENDPOINT => Some(config.authorization_endpoint.as_str()),
+                GET_TOKEN_ENDPOINT => Some(config.token_endpoint.as_str()),
+                JWKS_ENDPOINT => Some(config.jwks_uri.as_str()),
+                GET_DEVICE_CODE_ENDPOINT => config.device_authorization_endpoint.as_deref(),
+                USER_INFO_ENDPOINT => config.userinfo_endpoint.as_deref(),
+                REVOKE_TOKEN_ENDPOINT => config.revocation_endpoint.as_deref(),
+                OIDC_LOGOUT_ENDPOINT => config.end_session_endpoint.as_deref(),
+                PAR_ENDPOINT => config.pushed_authorization_request_endpoint.as_deref(),
+                BACKCHANNEL_AUTHORIZE_ENDPOINT => {
+                    config.backchannel_authentication_endpoint.as_deref()
+                }
+                _ => None,
+            });
+        Ok(self.0.domain.join(discovered.unwrap_or(endpoint))?)
+    }
+
+    /// Returns the expected issuer of the tokens, the discovered one if the discovery document
+    /// must be used, the tenant's domain otherwise.
+    pub(crate) async fn issuer(&self) -> Result<String> {
+        if self.0.use_discovery.load(Ordering::Relaxed) {
+            return Ok(self.discover().await?.issuer.clone());
+        }
+        Ok(self.0.domain.to_string())
+    }
+
+    /// Returns the application's Client ID.
+    pub(crate) fn client_id(&self) -> &str {
+        &self.0.client_id
+    }
+
+    /// Returns the application's Client Secret, if any.
+    pub(crate) fn client_secret(&self) -> Option<&str> {
+        self.0.client_secret.as_deref()
+    }
+
+    /// Returns the cache of the keys used to sign the tokens.
+    pub(crate) fn jwks(&self) -> &JwksCache {
+        &self.0.jwks
+    }
+
+    /// Send a GET request to the given endpoint.
+    pub(crate) async fn http_get<T>(&self, endpoint: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let request = self
+            .0
+            .client
+            .request(Method::GET, self.url(endpoint).await?);
+        let response = self.0.client.send(request).await?;
+        http::json_response(response).await
+    }
+
+    /// Send a GET request to the given endpoint, authenticated with the given access token.
+    async fn http_get_with_token<T>(&self, endpoint: &str, access_token: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let request = self
+            .0
+            .client
+            .request(Method::GET, self.url(endpoint).await?)
+            .bearer_auth(access_token);
+        let response = self.0.client.send(request).await?;
+        http::json_response(response).await
+    }
+
+    /// Send a POST request to the given endpoint, authenticated with the given access token.
+    async fn http_post_with_token<B, T>(
+        &self,
+        endpoint: &str,
+        access_token: &str,
+        json: &B,
+    ) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        let request = self
+            .0
+            .client
+            .post(self.url(endpoint).await?)
+            .bearer_auth(access_token)
+            .json(json);
+        let response = self.0.client.send(request).await?;
+        http::json_response(response).await
+    }
+
+    /// Send a DELETE request to the given endpoint, authenticated with the given access token.
+    async fn http_delete_with_token(&self, endpoint: &str, access_token: &str) -> Result<()> {
+        let request = self
+            .0
+            .client
+            .request(Method::DELETE, self.url(endpoint).await?)
+            .bearer_auth(access_token);
+        let response = self.0.client.send(request).await?;
+        http::empty_response(response).await
+    }
+
+    /// Send a POST request to the given endpoint.
+    async fn http_post<B, T>(&self, endpoint: &str, json: &B) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        self.http_post_with_policy(endpoint, json, self.0.client.retry_policy())
+            .await
+    }
+
+    /// Send a POST request to the given endpoint, retrying it according to the given policy.
+    async fn http_post_with_policy<B, T>(
+        &self,
+        endpoint: &str,
+        json: &B,
+        policy: RetryPolicy,
+    ) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        let body = self.authenticated_body(endpoint, json)?;
+        let request = self.0.client.post(self.url(endpoint).await?).json(&body);
+        let response = self.0.client.send_with_policy(request, policy).await?;
+        http::json_response(response).await
+    }
+
+    /// Send a POST request to the given endpoint, expecting an empty response.
+    async fn http_post_no_content<B: Serialize>(&self, endpoint: &str, json: &B) -> Result<()> {
+        let body = self.authenticated_body(endpoint, json)?;
+        let request = self.0.client.post(self.url(endpoint).await?).json(&body);
+        let response = self.0.client.send(request).await?;
+        http::empty_response(response).await
+    }
+
+    /// Send a form encoded POST request to the given endpoint.
+    async fn http_post_form<B, T>(&self, endpoint: &str, form: &B) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        let body = self.authenticated_body(endpoint, form)?;
+        let request = self.0.client.post(self.url(endpoint).await?).form(&body);
+        let response = self.0.client.send(request).await?;
+        http::json_response(response).await
+    }
+
+    /// Serialize the body of a POST request, adding a client assertion when the application
+    /// authenticates with a private key and the endpoint requires client authentication.
+    fn authenticated_body<B: Serialize>(&self, endpoint: &str, json: &B) -> Result<JsonValue> {
+        let mut body = serde_json::to_value(json)?;
+        if let (Some(signer), JsonValue::Object(map)) = (&self.0.client_assertion, &mut body) {
+            if CLIENT_AUTHENTICATION_ENDPOINTS.contains(&endpoint) {
+                let assertion = signer.sign(&self.0.client_id, self.0.domain.as_str())?;
+                map.insert("client_assertion".into(), assertion.into());
+                map.insert("client_assertion_type".into(), CLIENT_ASSERTION_TYPE.into());
+            }
+        }
+        Ok(body)
+    }
+
+    /// Build the URL to redirect the user to in order to authenticate, implementation of
+    /// [authorize application].
+    ///
+    /// The response type defaults to `code`, use [`AuthorizeUrlBuilder::pkce`] to add a PKCE
+    /// challenge to the request.
+    ///
+    /// [authorize application]: https://auth0.com/docs/api/authentication#authorize-application
+    pub fn authorize_url(&self) -> AuthorizeUrlBuilder {
+        let mut builder = AuthorizeUrlBuilder::default();
+        builder
+            .api(self.clone())
+            .client_id(self.0.client_id.clone());
+        builder
+    }
+
+    /// Build the URL to redirect the user to in order to authenticate, referencing an
+    /// authorization request previously pushed with [`AuthorizeUrlBuilder::push`].
+    pub fn pushed_authorize_url(&self, request_uri: &str) -> Result<Url> {
+        let mut url = self.cached_url(AUTHORIZE_ENDPOINT)?;
+        url.query_pairs_mut()
+            .append_pair("client_id", &self.0.client_id)
+            .append_pair("request_uri", request_uri);
+        Ok(url)
+    }
+
+    /// Build the URL to redirect the user to in order to log out, implementation of [logout].
+    ///
+    /// [logout]: https://auth0.com/docs/api/authentication#logout
+    pub fn logout_url(&self) -> LogoutUrlBuilder {
+        let mut builder = LogoutUrlBuilder::default();
+        builder
+            .api(self.clone())
+            .client_id(self.0.client_id.clone());
+        builder
+    }
+
+    /// Build the URL to redirect the user to in order to log out through the OpenID Connect
+    /// endpoint, implementation of [OIDC logout].
+    ///
+    /// [OIDC logout]: https://auth0.com/docs/api/authentication#oidc-logout
+    pub fn oidc_logout_url(&self) -> OidcLogoutUrlBuilder {
+        let mut builder = OidcLogoutUrlBuilder::default();
+        builder
+            .api(self.clone())
+            .client_id(self.0.client_id.clone());
+        builder
+    }
+
+    /// Request a device code for the application, implementation of [device authorization flow].
+    ///
+    /// [device authorization flow]: https://auth0.com/docs/api/authentication?shell#device-authorization-flow
+    pub fn get_device_code(&self) -> GetDeviceCodeBuilder {
+        let mut builder = GetDeviceCodeBuilder::default();
+        builder
+            .api(self.clone())
+            .client_id(self.0.client_id.clone());
+        builder
+    }
+
+    /// Poll the token endpoint until the user authorizes the device, second half of the [device
+    /// authorization flow].
+    ///
+    /// The endpoint is polled every `interval` seconds, as returned with the device code. The
+    /// interval is increased whenever Auth0 answers with `slow_down`, while `authorization_pending`
+    /// keeps the polling going. Any other error, such as `expired_token` or `access_denied`, is
+    /// returned to the caller. The polling stops with [`Error::Expired`] once the next attempt
+    /// would happen after the device code expires.
+    ///
+    /// [device authorization flow]: https://auth0.com/docs/api/authentication#device-authorization-flow
+    pub async fn poll_device_token(
+        &self,
+        device_code: &models::DeviceCode,
+    ) -> Result<models::AccessToken> {
+        let request = PollDeviceToken {
+            grant_type: GRANT_TYPE_DEVICE_CODE,
+            device_code: &device_code.device_code,
+            client_id: &self.0.client_id,
+        };
+        let interval = u64::try_from(device_code.interval).unwrap_or(u64::MAX);
+        let expires_in = u64::try_from(device_code.expires_in).unwrap_or(u64::MAX);
+        self.poll_token(&request, interval, expires_in).await
+    }
+
+    /// Start the authentication of a user on a separate device, first half of the [client
+    /// initiated backchannel authentication flow].
+    ///
+    /// The user is identified by its ID, and is asked to approve the request showing the given
+    /// binding message. The `openid` scope is always requested. The issuer of the login hint is
+    /// the discovered one when the discovery document is used.
+    ///
+    /// [client initiated backchannel authentication flow]: https://auth0.com/docs/get-started/authentication-and-authorization-flow/client-initiated-backchannel-authentication-flow
+    pub fn backchannel_authorize<U, M>(
+        &self,
+        user_id: U,
+        binding_message: M,
+    ) -> BackchannelAuthorizeBuilder
+    where
+        U: Into<String>,
+        M: Into<String>,
+    {
+        let mut builder = BackchannelAuthorizeBuilder::default();
+        builder
+            .api(self.clone())
+            .client_id(self.0.client_id.clone())
+            .client_secret(self.0.client_secret.clone())
+            .user_id(user_id)
+            .binding_message(binding_message)
+            .scope("openid");
+        builder
+    }
+
+    /// Poll the token endpoint until the user approves the request, second half of the [client
+    /// initiated backchannel authentication flow].
+    ///
+    /// The polling behaves as in [`AuthenticationApi::poll_device_token`].
+    ///
+    /// [client initiated backchannel authentication flow]: https://auth0.com/docs/get-started/authentication-and-authorization-flow/client-initiated-backchannel-authentication-flow
+    pub async fn poll_backchannel_token(
+        &self,
+        authorization: &models::BackchannelAuthorization,
+    ) -> Result<models::AccessToken> {
+        let request = PollBackchannelToken {
+            grant_type: GRANT_TYPE_CIBA,
+            auth_req_id: &authorization.auth_req_id,
+            client_id: &self.0.client_id,
+            client_secret: self.0.client_secret.as_deref(),
+        };
+        self.poll_token(&request, authorization.interval, authorization.expires_in)
+            .await
+    }
+
+    /// Poll the token endpoint every `interval` seconds, until the request is no longer pending or
+    /// expires after `expires_in` seconds.
+    async fn poll_token<B: Serialize>(
+        &self,
+        request: &B,
+        interval: u64,
+        expires_in: u64,
+    ) -> Result<models::AccessToken> {
+        // Only the time spent waiting between the attempts is accounted, Auth0 still answers
+        // `expired_token` if the requests themselves took long enough to reach the expiration.
+        let lifetime = Duration::from_secs(expires_in);
+        let mut waited = Duration::ZERO;
+        let mut interval = Duration::from_secs(interval.max(1));
+        loop {
+            waited = waited.saturating_add(interval);
+            if waited > lifetime {
+                return Err(Error::Expired);
+            }
+            tokio::time::sleep(interval).await;
+            // Auth0 answers `slow_down` with a 429 status code, which must not be retried.
+            let result = self
+                .http_post_with_policy(GET_TOKEN_ENDPOINT, request, RetryPolicy::none())
+                .await;
+            match result {
+                Err(err) if err.code() == Some(&ErrorCode::AuthorizationPending) => {}
+                Err(err) if err.code() == Some(&ErrorCode::SlowDown) => {
+                    interval = interval.saturating_add(SLOW_DOWN_INCREMENT);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Contains all the methods to continue a login that requires multi-factor authentication.
+    ///
+    /// The MFA token is returned along with the `mfa_required` error of the login, see
+    /// [`Error::mfa_token`].
+    pub fn mfa<T: Into<String>>(&self, mfa_token: T) -> Mfa {
+        let api = self.clone();
+        let mfa_token = mfa_token.into();
+        Mfa { api, mfa_token }
+    }
+
+    /// Retrieve the user's profile using an access token obtained during login, implementation of
+    /// [get user info].
+    ///
+    /// [get user info]: https://auth0.com/docs/api/authentication#get-user-info
+    pub fn user_info<T: Into<String>>(&self, access_token: T) -> GetUserInfoBuilder {
+        let mut builder = GetUserInfoBuilder::default();
+        builder.api(self.clone()).access_token(access_token);
+        builder
+    }
+
+    /// Verify an ID token issued to the application, validating its signature and claims.
+    ///
+    /// Only tokens signed with `RS256` are accepted by default, other algorithms must be allowed
+    /// explicitly. Tokens signed with `RS256` or `PS256` are verified with the keys published by the
+    /// tenant, which are cached and refreshed when a token is signed with an unknown key. Tokens
+    /// signed with `HS256` are verified with the client secret.
+    pub fn verify_id_token<T: Into<String>>(&self, token: T) -> VerifyIdTokenBuilder {
+        VerifyIdTokenBuilder::new(self.clone(), token.into())
+    }
+
+    /// Verify an access token issued for the given API, validating its signature and claims.
+    pub fn verify_access_token<T, U>(&self, token: T, audience: U) -> VerifyAccessTokenBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        VerifyAccessTokenBuilder::new(self.clone(), token.into(), audience.into())
+    }
+
+    /// Request an access token using the client's credentials, implementation of [client credentials
+    /// flow].
+    ///
+    /// [client credentials flow]: https://auth0.com/docs/api/authentication#client-credentials-flow
+    pub fn get_token<T: Into<String>>(&self, audience: T) -> Result<GetToken<'_>> {
+        if self.0.client_secret.is_none() && self.0.client_assertion.is_none() {
+            return Err(Error::MissingClientSecret);
+        }
+        Ok(GetToken {
+            api: self,
+            grant_type: GRANT_TYPE_CLIENT_CREDENTIALS,
+            client_id: &self.0.client_id,
+            client_secret: self.0.client_secret.as_deref(),
+            audience: audience.into(),
+        })
+    }
+
+    /// Request an access token using an authorization token, implementation of [authorization code
+    /// flow].
+    ///
+    /// [authorization code flow]: https://auth0.com/docs/api/authentication?http#authorization-code-flow47
+    pub fn get_token_with_auth_code<T: Into<String>>(
+        &self,
+        code: T,
+    ) -> Result<AuthorizationCodeLoginBuilder> {
+        if self.0.client_secret.is_none() && self.0.client_assertion.is_none() {
+            return Err(Error::MissingClientSecret);
+        }
+        let mut builder = AuthorizationCodeLoginBuilder::default();
+        builder
+            .api(self.clone())
+            .grant_type(GRANT_TYPE_AUTHORIZATION_CODE)
+            .client_id(self.0.client_id.clone())
+            .client_secret(self.0.client_secret.clone())
+            .code(code);
+        Ok(builder)
+    }
+
+    /// Request an access token using an authorization token with PKCE, implementation of
+    /// [authorization code flow with PKCE].
+    ///
+    /// [authorization code flow with PKCE]: https://auth0.com/docs/api/authentication?http#authorization-code-flow-with-pkce48
+    pub fn get_token_with_auth_code_pkce<T, U>(
+        &self,
+        code: T,
+        code_verifier: U,
+    ) -> Result<AuthorizationCodeLoginBuilder>
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = AuthorizationCodeLoginBuilder::default();
+        builder
+            .api(self.clone())
+            .grant_type(GRANT_TYPE_AUTHORIZATION_CODE)
+            .client_id(self.0.client_id.clone())
+            .code(code)
+            .code_verifier(code_verifier);
+        Ok(builder)
+    }
+
+    /// Request an access token using the user's credentials, implementation of [resource owner password].
+    ///
+    /// [resource owner password]: https://auth0.com/docs/api/authentication?javascript#resource-owner-password
+    pub fn login<U, P>(&self, username: U, password: P) -> UserLoginBuilder
+    where
+        U: Into<String>,
+        P: Into<String>,
+    {
+        let mut builder = UserLoginBuilder::default();
+        builder
+            .api(self.clone())
+            .grant_type(GRANT_TYPE_RESOURCE_OWNED_PASSWORD)
+            .client_id(self.0.client_id.clone())
+            .client_secret(self.0.client_secret.clone())
+            .username(username)
+            .password(password);
+        builder
+    }
+
+    /// Create a new user in a database connection, implementation of [signup].
+    ///
+    /// [signup]: https://auth0.com/docs/api/authentication#signup
+    pub fn signup<E, P, C>(&self, email: E, password: P, connection: C) -> SignupBuilder
+    where
+        E: Into<String>,
+        P: Into<String>,
+        C: Into<String>,
+    {
+        let mut builder = SignupBuilder::default();
+        builder
+            .api(self.clone())
+            .client_id(self.0.client_id.clone())
+            .email(email)
+            .password(password)
+            .connection(connection);
+        builder
+    }
+
+    /// Send an email with a link to reset the password of a user in a database connection,
+    /// implementation of [change password].
+    ///
+    /// [change password]: https://auth0.com/docs/api/authentication#change-password
+    pub fn change_password<E, C>(&self, email: E, connection: C) -> ChangePasswordBuilder
+    where
+        E: Into<String>,
+        C: Into<String>,
+    {
+        let mut builder = ChangePasswordBuilder::default();
+        builder
+            .api(self.clone())
+            .client_id(self.0.client_id.clone())
+            .email(email)
+            .connection(connection);
+        builder
+    }
+
+    /// Send a one-time code or a magic link to the user, implementation of [passwordless start].
+    ///
+    /// Either [`PasswordlessStartBuilder::email`] or [`PasswordlessStartBuilder::phone_number`]
+    /// must be set, selecting the `email` or the `sms` connection respectively.
+    ///
+    /// [passwordless start]: https://auth0.com/docs/api/authentication#get-code-or-link
+    pub fn passwordless_start(&self) -> PasswordlessStartBuilder {
+        let mut builder = PasswordlessStartBuilder::default();
+        builder
+            .api(self.clone())
+            .client_id(self.0.client_id.clone())
+            .client_secret(self.0.client_secret.clone());
+        builder
+    }
+
+    /// Get an access token by using the one-time code received by the user, implementation of
+    /// [passwordless login].
+    ///
+    /// The username is the email address or the phone number the code was sent to.
+    ///
+    /// [passwordless login]: https://auth0.com/docs/api/authentication#authenticate-user
+    pub fn passwordless_login<U, O>(
+        &self,
+        realm: PasswordlessConnection,
+        username: U,
+        otp: O,
+    ) -> PasswordlessLoginBuilder
+    where
+        U: Into<String>,
+        O: Into<String>,
+    {
+        let mut builder = PasswordlessLoginBuilder::default();
+        builder
+            .api(self.clone())
+            .grant_type(GRANT_TYPE_PASSWORDLESS_OTP)
+            .client_id(self.0.client_id.clone())
+            .client_secret(self.0.client_secret.clone())
+            .realm(realm)
+            .username(username)
+            .otp(otp);
+        builder
+    }
+
+    /// Request a new access token using a refresh token, implementation of [refresh token].
+    ///
+    /// [refresh token]: https://auth0.com/docs/api/authentication#refresh-token
+    pub fn refresh_token<T: Into<String>>(&self, refresh_token: T) -> RefreshTokenBuilder {
+        let mut builder = RefreshTokenBuilder::default();
+        builder
+            .api(self.clone())
+            .grant_type(GRANT_TYPE_REFRESH_TOKEN)
+            .client_id(self.0.client_id.clone())
+            .client_secret(self.0.client_secret.clone())
+            .refresh_token(refresh_token);
+        builder
+    }
+
+    /// Exchange a token issued by another system for Auth0 tokens, implementation of [token
+    /// exchange].
+    ///
+    /// The subject token type must match the one of a Custom Token Exchange profile of the
+    /// tenant, Auth0 answers `invalid_request` or `unsupported_token_type` otherwise.
+    ///
+    /// [token exchange]: https://auth0.com/docs/authenticate/custom-token-exchange
+    pub fn token_exchange<T, U>(
+        &self,
+        subject_token: T,
+        subject_token_type: U,
+    ) -> TokenExchangeBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = TokenExchangeBuilder::default();
+        builder
+            .api(self.clone())
+            .grant_type(GRANT_TYPE_TOKEN_EXCHANGE)
+            .client_id(self.0.client_id.clone())
+            .client_secret(self.0.client_secret.clone())
+            .subject_token(subject_token)
+            .subject_token_type(subject_token_type);
+        builder
+    }
+
+    /// Invalidate a refresh token, implementation of [revoke refresh token].
+    ///
+    /// The client secret is sent when configured, as required for confidential applications.
+    /// Auth0 replies with `invalid_request` when the token is missing, and with `invalid_client`
+    /// or `unauthorized_client` when the application cannot revoke it.
+    ///
+    /// [revoke refresh token]: https://auth0.com/docs/api/authentication#revoke-refresh-token
+    pub fn revoke_refresh_token<T: Into<String>>(&self, token: T) -> RevokeRefreshTokenBuilder {
+        let mut builder = RevokeRefreshTokenBuilder::default();
+        builder
+            .api(self.clone())
+            .client_id(self.0.client_id.clone())
+            .client_secret(self.0.client_secret.clone())
+            .token(token);
+        builder
+    }
+}
+
+/// A PKCE code verifier and its `S256` challenge.
+///
+/// The challenge is sent with the authorization request, while the verifier is kept by the
+/// application and sent when exchanging the authorization code for the tokens.
+#[derive(Clone)]
+pub struct Pkce {
+    /// The code verifier.
+    verifier: String,
+    /// The code challenge.
+    challenge: String,
+}
+
+impl Pkce {
+    /// Method used to derive the challenge from the verifier.
+    pub const METHOD: &'static str = "S256";
+
+    /// Generate a new random code verifier and its challenge.
+    pub fn new() -> Self {
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        Self::from_verifier(URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    /// Compute the challenge of the given code verifier.
+    pub fn from_verifier<T: Into<String>>(verifier: T) -> Self {
+        let verifier = verifier.into();
+        let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+        Self {
+            verifier,
+            challenge,
+        }
+    }
+
+    /// Returns the code verifier.
+    pub fn verifier(&self) -> &str {
+        &self.verifier
+    }
+
+    /// Returns the code challenge.
+    pub fn challenge(&self) -> &str {
+        &self.challenge
+    }
+}
+
+impl Default for Pkce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for Pkce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pkce")
+            .field("challenge", &self.challenge)
+            .finish()
+    }
+}
+
+/// Build the URL of an authorization request.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct AuthorizeUrl {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Specifies the token type. Use `code` for authorization code flows. Default is `code`.
+    #[builder(setter(into), default = "String::from(\"code\")")]
+    response_type: String,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// The URL to which Auth0 will redirect the browser after authorization has been granted by
+    /// the user.
+    #[builder(setter(strip_option, into), default)]
+    redirect_uri: Option<String>,
+    /// The scopes which you want to request authorization for. These must be separated by a space.
+    #[serde_as(as = "StringWithSeparator::<SpaceSeparator, String>")]
+    #[builder(setter(custom), default)]
+    scope: Vec<String>,
+    /// The unique identifier of the target API you want to access.
+    #[builder(setter(strip_option, into), default)]
+    audience: Option<String>,
+    /// An opaque value the application adds to the initial request that Auth0 includes when
+    /// redirecting back to the application.
+    #[builder(setter(strip_option, into), default)]
+    state: Option<String>,
+    /// A string value which will be included in the ID token response from Auth0, used to
+    /// prevent token replay attacks.
+    #[builder(setter(strip_option, into), default)]
+    nonce: Option<String>,
+    /// The name of the connection configured for your application.
+    #[builder(setter(strip_option, into), default)]
+    connection: Option<String>,
+    /// ID of the organization to use when authenticating a user.
+    #[builder(setter(strip_option, into), default)]
+    organization: Option<String>,
+    /// Ticket ID of the organization invitation.
+    #[builder(setter(strip_option, into), default)]
+    invitation: Option<String>,
+    /// To initiate a silent authentication request, use `none`. To show the login page, use
+    /// `login`.
+    #[builder(setter(strip_option, into), default)]
+    prompt: Option<String>,
+    /// Whether the Universal Login page should show the `login` or the `signup` screen.
+    #[builder(setter(strip_option, into), default)]
+    screen_hint: Option<String>,
+    /// Specifies how the result of the authorization request is returned, such as `query`,
+    /// `fragment`, `form_post` or `web_message`.
+    #[builder(setter(strip_option, into), default)]
+    response_mode: Option<String>,
+    /// The challenge generated from the `code_verifier`.
+    #[builder(setter(custom), default)]
+    code_challenge: Option<String>,
+    /// Method used to generate the challenge.
+    #[builder(setter(custom), default)]
+    code_challenge_method: Option<&'static str>,
+}
+
+impl AuthorizeUrlBuilder {
+    /// Build the URL.
+    pub fn url(&self) -> Result<Url> {
+        let request = self.build()?;
+        let mut url = request.api.cached_url(AUTHORIZE_ENDPOINT)?;
+        url.set_query(Some(&serde_urlencoded::to_string(&request)?));
+        Ok(url)
+    }
+
+    /// Push the authorization request to Auth0, implementation of [pushed authorization
+    /// request].
+    ///
+    /// The returned `request_uri` is then used to build the URL to redirect the user to, see
+    /// [`AuthenticationApi::pushed_authorize_url`].
+    ///
+    /// [pushed authorization request]: https://auth0.com/docs/api/authentication#authorization-code-flow-with-par
+    pub async fn push(&self) -> Result<models::PushedAuthorizationRequest> {
+        let request = self.build()?;
+        let form = PushAuthorizeUrl {
+            request: &request,
+            client_secret: request.api.client_secret(),
+        };
+        request.api.http_post_form(PAR_ENDPOINT, &form).await
+    }
+
+    /// Add the challenge of the given PKCE code verifier to the request.
+    pub fn pkce(&mut self, pkce: &Pkce) -> &mut Self {
+        self.code_challenge = Some(Some(pkce.challenge.clone()));
+        self.code_challenge_method = Some(Some(Pkce::METHOD));
+        self
+    }
+
+    /// Append one element to the list of scope.
+    pub fn scope<T: Into<String>>(&mut self, scope: T) -> &mut Self {
+        self.scope.get_or_insert_with(Vec::new).push(scope.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of scopes.
+    pub fn scopes<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.scope
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Body of a pushed authorization request.
+#[derive(Serialize)]
+struct PushAuthorizeUrl<'a> {
+    /// The parameters of the authorization request.
+    #[serde(flatten)]
+    request: &'a AuthorizeUrl,
+    /// Application's Client Secret.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_secret: Option<&'a str>,
+}
+
+/// Build the URL of a logout request.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct LogoutUrl {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// URL to redirect the user after the logout, it must be listed in the allowed logout URLs.
+    #[serde(rename = "returnTo")]
+    #[builder(setter(strip_option, into), default)]
+    return_to: Option<String>,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Whether to log the user out of the identity provider too.
+    #[serde(skip)]
+    #[builder(default)]
+    federated: bool,
+}
+
+impl LogoutUrlBuilder {
+    /// Build the URL.
+    pub fn url(&self) -> Result<Url> {
+        let request = self.build()?;
+        let mut url = request.api.cached_url(LOGOUT_ENDPOINT)?;
+        url.set_query(Some(&serde_urlencoded::to_string(&request)?));
+        if request.federated {
+            url.query_pairs_mut().append_key_only("federated");
+        }
+        Ok(url)
+    }
+}
+
+/// Build the URL of an OpenID Connect logout request.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct OidcLogoutUrl {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// The ID token previously issued to the user.
+    #[builder(setter(strip_option, into), default)]
+    id_token_hint: Option<String>,
+    /// Optional `sid` (session ID) value to indicate which user to log out.
+    #[builder(setter(strip_option, into), default)]
+    logout_hint: Option<String>,
+    /// URL to redirect the user after the logout, it must be listed in the allowed logout URLs.
+    #[builder(setter(strip_option, into), default)]
+    post_logout_redirect_uri: Option<String>,
+    /// An opaque value that Auth0 will return as is in the redirect.
+    #[builder(setter(strip_option, into), default)]
+    state: Option<String>,
+    /// Space-delimited list of locales used to display the logout prompt.
+    #[builder(setter(strip_option, into), default)]
+    ui_locales: Option<String>,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+}
+
+impl OidcLogoutUrlBuilder {
+    /// Build the URL.
+    pub fn url(&self) -> Result<Url> {
+        let request = self.build()?;
+        let mut url = request.api.cached_url(OIDC_LOGOUT_ENDPOINT)?;
+        url.set_query(Some(&serde_urlencoded::to_string(&request)?));
+        Ok(url)
+    }
+}
+
+/// Get a device code for an application.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct GetDeviceCode {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// The unique identifier of the target API you want to access.
+    #[builder(setter(strip_option, into), default)]
+    audience: Option<String>,
+    /// The scopes for which you want to request authorization. These must be separated by a space.
+    #[serde_as(as = "StringWithSeparator::<SpaceSeparator, String>")]
+    #[builder(setter(custom), default)]
+    scope: Vec<String>,
+}
+
+impl GetDeviceCodeBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::DeviceCode> {
+        let request = self.build()?;
+        request
+            .api
+            .http_post(GET_DEVICE_CODE_ENDPOINT, &request)
+            .await
+    }
+
+    /// Append one element to the list of scope.
+    pub fn scope<T: Into<String>>(&mut self, scope: T) -> &mut Self {
+        self.scope.get_or_insert_with(Vec::new).push(scope.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of scopes.
+    pub fn scopes<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.scope
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Poll the token endpoint with a device code.
+#[derive(Debug, Serialize)]
+struct PollDeviceToken<'a> {
+    /// Denotes the flow you are using.
+    grant_type: &'static str,
+    /// The device code previously returned from the `/oauth/device/code` endpoint.
+    device_code: &'a str,
+    /// Application's Client ID.
+    client_id: &'a str,
+}
+
+/// Poll the token endpoint with a backchannel authentication request.
+#[derive(Serialize)]
+struct PollBackchannelToken<'a> {
+    /// Denotes the flow you are using.
+    grant_type: &'static str,
+    /// The ID of the request previously returned from the `/bc-authorize` endpoint.
+    auth_req_id: &'a str,
+    /// Application's Client ID.
+    client_id: &'a str,
+    /// Application's Client Secret.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_secret: Option<&'a str>,
+}
+
+/// Start a backchannel authentication.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct BackchannelAuthorize {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// ID of the user to authenticate.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    user_id: String,
+    /// The user to authenticate, in the `iss_sub` format, filled in when sending the request.
+    #[builder(setter(skip))]
+    login_hint: String,
+    /// The message shown to the user on the authentication device.
+    #[builder(private, setter(into))]
+    binding_message: String,
+    /// String value of the different scopes the application is asking for. Multiple scopes are
+    /// separated with whitespace.
+    #[serde_as(as = "StringWithSeparator::<SpaceSeparator, String>")]
+    #[builder(setter(custom), default)]
+    scope: Vec<String>,
+    /// The unique identifier of the target API you want to access.
+    #[builder(setter(strip_option, into), default)]
+    audience: Option<String>,
+    /// The lifetime of the request, in seconds.
+    #[builder(setter(strip_option), default)]
+    requested_expiry: Option<u64>,
+}
+
+impl BackchannelAuthorizeBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::BackchannelAuthorization> {
+        let mut request = self.build()?;
+        request.login_hint = json!({
+            "format": "iss_sub",
+            "iss": request.api.issuer().await?,
+            "sub": &request.user_id,
+        })
+        .to_string();
+        request
+            .api
+            .http_post_form(BACKCHANNEL_AUTHORIZE_ENDPOINT, &request)
+            .await
+    }
+
+    /// Append one element to the list of scope.
+    pub fn scope<T: Into<String>>(&mut self, scope: T) -> &mut Self {
+        self.scope.get_or_insert_with(Vec::new).push(scope.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of scopes.
+    pub fn scopes<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.scope
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Get an access token by using the client's credentials.
+#[derive(Debug, Serialize)]
+pub struct GetToken<'a> {
+    #[serde(skip)]
+    api: &'a AuthenticationApi,
+    /// Denotes the flow you are using.
+    grant_type: &'static str,
+    /// Application's Client ID.
+    client_id: &'a str,
+    /// Application's Client Secret.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_secret: Option<&'a str>,
+    /// The unique identifier of the target API you want to access.
+    audience: String,
+}
+
+impl<'a> GetToken<'a> {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::AccessToken> {
+        self.api.http_post(GET_TOKEN_ENDPOINT, self).await
+    }
+}
+
+/// Request an access token using an authorization token.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+#[builder(derive(Debug))]
+pub struct AuthorizationCodeLogin {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Denotes the flow you are using.
+    #[builder(private)]
+    grant_type: &'static str,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private, default)]
+    client_secret: Option<String>,
+    /// The Authorization Code received from the initial `/authorize` call.
+    #[builder(private, setter(into))]
+    code: String,
+    /// Cryptographically random key that was used to generate the `code_challenge` passed to
+    /// `/authorize`.
+    #[builder(private, setter(strip_option, into), default)]
+    code_verifier: Option<String>,
+    /// This is required only if it was set at the GET /authorize endpoint. The values must match.
+    #[builder(setter(strip_option, into), default)]
+    redirect_uri: Option<String>,
+}
+
+impl AuthorizationCodeLoginBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::AccessToken> {
+        let request = self.build()?;
+        request.api.http_post(GET_TOKEN_ENDPOINT, &request).await
+    }
+}
+
+/// Get an access token by using user's credentials.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct UserLogin {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Denotes the flow you are using.
+    #[builder(private)]
+    grant_type: &'static str,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// The unique identifier of the target API you want to access.
+    #[builder(setter(strip_option, into), default)]
+    audience: Option<String>,
+    /// Resource Owner's identifier, such as a username or email address.
+    #[builder(private, setter(into))]
+    username: String,
+    /// Resource Owner's secret.
+    #[builder(private, setter(into))]
+    password: String,
+    /// String value of the different scopes the application is asking for. Multiple scopes are
+    /// separated with whitespace.
+    #[serde_as(as = "StringWithSeparator::<SpaceSeparator, String>")]
+    #[builder(setter(custom), default)]
+    scope: Vec<String>,
+    /// String value of the realm the user belongs. Set this if you want to add realm support at this grant.
+    #[builder(setter(strip_option, into), default)]
+    realm: Option<String>,
+}
+
+impl UserLoginBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::AccessToken> {
+        let request = self.build()?;
+        request.api.http_post(GET_TOKEN_ENDPOINT, &request).await
+    }
+
+    /// Append one element to the list of scope.
+    pub fn scope<T: Into<String>>(&mut self, scope: T) -> &mut Self {
+        self.scope.get_or_insert_with(Vec::new).push(scope.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of scopes.
+    pub fn scopes<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.scope
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Create a new user in a database connection.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct Signup {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// The user's email address.
+    #[builder(private, setter(into))]
+    email: String,
+    /// The user's desired password.
+    #[builder(private, setter(into))]
+    password: String,
+    /// The name of the database connection where the user will be created.
+    #[builder(private, setter(into))]
+    connection: String,
+    /// The user's username, only valid if the connection requires a username.
+    #[builder(setter(strip_option, into), default)]
+    username: Option<String>,
+    /// The user's given name.
+    #[builder(setter(strip_option, into), default)]
+    given_name: Option<String>,
+    /// The user's family name.
+    #[builder(setter(strip_option, into), default)]
+    family_name: Option<String>,
+    /// The user's full name.
+    #[builder(setter(strip_option, into), default)]
+    name: Option<String>,
+    /// The user's nickname.
+    #[builder(setter(strip_option, into), default)]
+    nickname: Option<String>,
+    /// A URI pointing to the user's picture.
+    #[builder(setter(strip_option, into), default)]
+    picture: Option<String>,
+    /// The user metadata to be associated with the user.
+    #[builder(setter(strip_option), default)]
+    user_metadata: Option<JsonValue>,
+}
+
+impl SignupBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<SignupResponse> {
+        let request = self.build()?;
+        request.api.http_post(SIGNUP_ENDPOINT, &request).await
+    }
+}
+
+/// The user created by a signup.
+pub type SignupResponse = models::SignupUser;
+
+/// Send an email to reset the password of a user.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ChangePassword {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// The user's email address.
+    #[builder(private, setter(into))]
+    email: String,
+    /// The name of the database connection the user belongs to.
+    #[builder(private, setter(into))]
+    connection: String,
+    /// The organization ID the user belongs to, used to customize the email.
+    #[builder(setter(strip_option, into), default)]
+    organization: Option<String>,
+}
+
+impl ChangePasswordBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        request
+            .api
+            .http_post_no_content(CHANGE_PASSWORD_ENDPOINT, &request)
+            .await
+    }
+}
+
+/// Passwordless connection used to reach the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PasswordlessConnection {
+    /// Send the code or the link by email.
+    Email,
+    /// Send the code by SMS.
+    Sms,
+}
+
+/// What to send to the user to complete a passwordless authentication.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PasswordlessSend {
+    /// A magic link to click.
+    Link,
+    /// A one-time code to enter.
+    Code,
+}
+
+/// Start a passwordless authentication.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct PasswordlessStart {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// How to send the code or the link, set along with the email or the phone number.
+    #[builder(setter(custom))]
+    connection: PasswordlessConnection,
+    /// The user's email address.
+    #[builder(setter(custom), default)]
+    email: Option<String>,
+    /// The user's phone number.
+    #[builder(setter(custom), default)]
+    phone_number: Option<String>,
+    /// Whether to send a magic link or a code. Auth0 sends a link when not set.
+    #[serde(rename = "send")]
+    #[builder(setter(strip_option), default)]
+    delivery: Option<PasswordlessSend>,
+    /// Parameters of the authorization request performed when the magic link is clicked, such
+    /// as `scope`, `state` or `redirect_uri`.
+    #[serde(rename = "authParams")]
+    #[builder(setter(custom), default)]
+    auth_params: Option<HashMap<String, JsonValue>>,
+}
+
+impl PasswordlessStartBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::PasswordlessStart> {
+        let request = self.build()?;
+        request
+            .api
+            .http_post(PASSWORDLESS_START_ENDPOINT, &request)
+            .await
+    }
+
+    /// Send the code or the link to the given email address, using the `email` connection.
+    pub fn email<T: Into<String>>(&mut self, email: T) -> &mut Self {
+        self.connection = Some(PasswordlessConnection::Email);
+        self.email = Some(Some(email.into()));
+        self.phone_number = None;
+        self
+    }
+
+    /// Send the code to the given phone number, using the `sms` connection.
+    pub fn phone_number<T: Into<String>>(&mut self, phone_number: T) -> &mut Self {
+        self.connection = Some(PasswordlessConnection::Sms);
+        self.phone_number = Some(Some(phone_number.into()));
+        self.email = None;
+        self
+    }
+
+    /// Add a parameter of the authorization request.
+    pub fn auth_param<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<String>,
+        V: Into<JsonValue>,
+    {
+        self.auth_params
+            .get_or_insert_with(|| Some(HashMap::new()))
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Get an access token by using a passwordless one-time code.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct PasswordlessLogin {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Denotes the flow you are using.
+    #[builder(private)]
+    grant_type: &'static str,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// The connection the code was sent with.
+    #[builder(private)]
+    realm: PasswordlessConnection,
+    /// The user's email address or phone number.
+    #[builder(private, setter(into))]
+    username: String,
+    /// The code received by the user.
+    #[builder(private, setter(into))]
+    otp: String,
+    /// The unique identifier of the target API you want to access.
+    #[builder(setter(strip_option, into), default)]
+    audience: Option<String>,
+    /// String value of the different scopes the application is asking for. Multiple scopes are
+    /// separated with whitespace.
+    #[serde_as(as = "StringWithSeparator::<SpaceSeparator, String>")]
+    #[builder(setter(custom), default)]
+    scope: Vec<String>,
+}
+
+impl PasswordlessLoginBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::AccessToken> {
+        let request = self.build()?;
+        request.api.http_post(GET_TOKEN_ENDPOINT, &request).await
+    }
+
+    /// Append one element to the list of scope.
+    pub fn scope<T: Into<String>>(&mut self, scope: T) -> &mut Self {
+        self.scope.get_or_insert_with(Vec::new).push(scope.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of scopes.
+    pub fn scopes<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.scope
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Get a new access token by using a refresh token.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct RefreshToken {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Denotes the flow you are using.
+    #[builder(private)]
+    grant_type: &'static str,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// The refresh token to use.
+    #[builder(private, setter(into))]
+    refresh_token: String,
+    /// A space-delimited list of requested scope permissions. If not sent, the original scopes
+    /// will be used; otherwise you can request a reduced set of scopes.
+    #[serde_as(as = "StringWithSeparator::<SpaceSeparator, String>")]
+    #[builder(setter(custom), default)]
+    scope: Vec<String>,
+}
+
+impl RefreshTokenBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::AccessToken> {
+        let request = self.build()?;
+        request.api.http_post(GET_TOKEN_ENDPOINT, &request).await
+    }
+
+    /// Append one element to the list of scope.
+    pub fn scope<T: Into<String>>(&mut self, scope: T) -> &mut Self {
+        self.scope.get_or_insert_with(Vec::new).push(scope.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of scopes.
+    pub fn scopes<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.scope
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Get the user's profile.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct GetUserInfo {
+    #[builder(private)]
+    api: AuthenticationApi,
+    /// The access token obtained during login.
+    #[builder(private, setter(into))]
+    access_token: String,
+}
+
+impl GetUserInfoBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::UserInfo> {
+        let request = self.build()?;
+        request
+            .api
+            .http_get_with_token(USER_INFO_ENDPOINT, &request.access_token)
+            .await
+    }
+}
+
+/// Exchange a token for Auth0 tokens.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct TokenExchange {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Denotes the flow you are using.
+    #[builder(private)]
+    grant_type: &'static str,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// The token to exchange.
+    #[builder(private, setter(into))]
+    subject_token: String,
+    /// The type of the token to exchange, a URI identifying the token exchange profile.
+    #[builder(private, setter(into))]
+    subject_token_type: String,
+    /// The unique identifier of the target API you want to access.
+    #[builder(setter(strip_option, into), default)]
+    audience: Option<String>,
+    /// String value of the different scopes the application is asking for. Multiple scopes are
+    /// separated with whitespace.
+    #[serde_as(as = "StringWithSeparator::<SpaceSeparator, String>")]
+    #[builder(setter(custom), default)]
+    scope: Vec<String>,
+    /// The organization to log the user in to.
+    #[builder(setter(strip_option, into), default)]
+    organization: Option<String>,
+}
+
+impl TokenExchangeBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<models::AccessToken> {
+        let request = self.build()?;
+        request.api.http_post(GET_TOKEN_ENDPOINT, &request).await
+    }
+
+    /// Append one element to the list of scope.
+    pub fn scope<T: Into<String>>(&mut self, scope: T) -> &mut Self {
+        self.scope.get_or_insert_with(Vec::new).push(scope.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of scopes.
+    pub fn scopes<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.scope
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Revoke a refresh token.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct RevokeRefreshToken {
+    #[builder(private)]
+    #[serde(skip)]
+    api: AuthenticationApi,
+    /// Application's Client ID.
+    #[builder(private)]
+    client_id: String,
+    /// Application's Client Secret.
+    #[builder(private)]
+    client_secret: Option<String>,
+    /// The refresh token to revoke.
+    #[builder(private, setter(into))]
+    token: String,
+}
+
+impl RevokeRefreshTokenBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        request
+            .api
+            .http_post_no_content(REVOKE_TOKEN_ENDPOINT, &request)
+            .await
+    }
+}
@@ -1,11 +1,15 @@
 //! Implementation of the Clients methods of the Management API.
 
-use anyhow::Result;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::{formats::CommaSeparator, serde_as, StringWithSeparator};
 
-use crate::{models, ManagementApi};
+use crate::{
+    http::path_segment,
+    models,
+    pagination::{Page, PageStream, Paginated, Pagination},
+    ManagementApi, Result,
+};
 
 const CLIENTS_ENDPOINT: &str = "/api/v2/clients";
 
@@ -32,6 +36,46 @@ impl Clients {
         builder.api(self.api.clone()).id(id);
         builder
     }
+
+    /// Create a new client, implementation of [`/api/v2/clients`] endpoint.
+    ///
+    /// [`/api/v2/clients`]: https://auth0.com/docs/api/management/v2/clients/post-clients
+    pub fn create(&self, settings: models::ClientSettings) -> CreateClientBuilder {
+        let mut builder = CreateClientBuilder::default();
+        builder.api(self.api.clone()).settings(settings);
+        builder
+    }
+
+    /// Update a client, implementation of [`/api/v2/clients/{id}`] endpoint.
+    ///
+    /// [`/api/v2/clients/{id}`]: https://auth0.com/docs/api/management/v2/clients/patch-clients-by-id
+    pub fn update<T: Into<String>>(
+        &self,
+        id: T,
+        settings: models::ClientSettings,
+    ) -> UpdateClientBuilder {
+        let mut builder = UpdateClientBuilder::default();
+        builder.api(self.api.clone()).id(id).settings(settings);
+        builder
+    }
+
+    /// Delete a client, implementation of [`/api/v2/clients/{id}`] endpoint.
+    ///
+    /// [`/api/v2/clients/{id}`]: https://auth0.com/docs/api/management/v2/clients/delete-clients-by-id
+    pub fn delete<T: Into<String>>(&self, id: T) -> DeleteClientBuilder {
+        let mut builder = DeleteClientBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Rotate a client secret, implementation of [`/api/v2/clients/{id}/rotate-secret`] endpoint.
+    ///
+    /// [`/api/v2/clients/{id}/rotate-secret`]: https://auth0.com/docs/api/management/v2/clients/post-rotate-secret
+    pub fn rotate_secret<T: Into<String>>(&self, id: T) -> RotateClientSecretBuilder {
+        let mut builder = RotateClientSecretBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
 }
 
 /// Retrieve clients (applications and SSO integrations) matching provided filters.
@@ -40,8 +84,8 @@ impl Clients {
     Option => #[serde(skip_serializing_if = "Option::is_none")],
     Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
 )]
-#[derive(Builder, Debug, Serialize)]
-#[builder(build_fn(private, error = "anyhow::Error"))]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
 pub struct ListClients {
     #[builder(private)]
     #[serde(skip)]
@@ -89,14 +133,13 @@ pub struct ListClientsResponse {
     pub total: Option<usize>,
 }
 
-impl ListClientsBuilder {
+impl ListClients {
     /// Send the API request.
-    pub async fn send(&self) -> Result<ListClientsResponse> {
-        let request = self.build()?;
-        if request.include_totals.unwrap_or(false) {
-            request.api.http_get(CLIENTS_ENDPOINT, &request).await
+    async fn send(&self) -> Result<ListClientsResponse> {
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(CLIENTS_ENDPOINT, self).await
         } else {
-            let clients = request.api.http_get(CLIENTS_ENDPOINT, &request).await?;
+            let clients = self.api.http_get(CLIENTS_ENDPOINT, self).await?;
             Ok(ListClientsResponse {
                 start: None,
                 limit: None,
@@ -106,6 +149,34 @@ impl ListClientsBuilder {
             })
         }
     }
+}
+
+impl Paginated for ListClients {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListClientsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListClientsResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::Client> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.clients,
+                total: response.total,
+            })
+        })
+    }
 
     /// Append one element to the list of `fields`.
     pub fn field<T: Into<String>>(&mut self, field: T) -> &mut Self {
@@ -153,7 +224,7 @@ impl ListClientsBuilder {
     Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
 )]
 #[derive(Builder, Debug, Serialize)]
-#[builder(build_fn(private, error = "anyhow::Error"))]
+#[builder(build_fn(private, error = "crate::Error"))]
 pub struct GetClient {
     #[builder(private)]
     #[serde(skip)]
@@ -178,7 +249,7 @@ impl GetClientBuilder {
     /// Send the API request.
     pub async fn send(&self) -> Result<GetClientResponse> {
         let request = self.build()?;
-        let endpoint = format!("{}/{}", CLIENTS_ENDPOINT, request.id);
+        let endpoint = format!("{}/{}", CLIENTS_ENDPOINT, path_segment(&request.id));
         request.api.http_get(&endpoint, &request).await
     }
 
@@ -200,3 +271,103 @@ impl GetClientBuilder {
         self
     }
 }
+
+/// Create a new client.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct CreateClient {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// Settings of the client.
+    #[builder(private)]
+    #[serde(flatten)]
+    settings: models::ClientSettings,
+}
+
+/// Response for [`CreateClient`].
+pub type CreateClientResponse = models::Client;
+
+impl CreateClientBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<CreateClientResponse> {
+        let request = self.build()?;
+        request.api.http_post(CLIENTS_ENDPOINT, &request).await
+    }
+}
+
+/// Update a client.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct UpdateClient {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the client to update.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Settings of the client to update.
+    #[builder(private)]
+    #[serde(flatten)]
+    settings: models::ClientSettings,
+}
+
+/// Response for [`UpdateClient`].
+pub type UpdateClientResponse = models::Client;
+
+impl UpdateClientBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<UpdateClientResponse> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", CLIENTS_ENDPOINT, path_segment(&request.id));
+        request.api.http_patch(&endpoint, &request).await
+    }
+}
+
+/// Delete a client.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct DeleteClient {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the client to delete.
+    #[builder(private, setter(into))]
+    id: String,
+}
+
+impl DeleteClientBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", CLIENTS_ENDPOINT, path_segment(&request.id));
+        request.api.http_delete(&endpoint).await
+    }
+}
+
+/// Rotate a client secret.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct RotateClientSecret {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the client that will rotate secrets.
+    #[builder(private, setter(into))]
+    id: String,
+}
+
+/// Response for [`RotateClientSecret`].
+pub type RotateClientSecretResponse = models::Client;
+
+impl RotateClientSecretBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<RotateClientSecretResponse> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/rotate-secret",
+            CLIENTS_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request.api.http_post_empty(&endpoint).await
+    }
+}
@@ -0,0 +1,517 @@
+//! Implementation of the Connections methods of the Management API.
+
+use std::collections::HashMap;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::{formats::CommaSeparator, serde_as, StringWithSeparator};
+
+use crate::{
+    http::path_segment,
+    models,
+    pagination::{Page, PageStream, Paginated, Pagination},
+    ManagementApi, Result,
+};
+
+const CONNECTIONS_ENDPOINT: &str = "/api/v2/connections";
+
+/// This struct provides an implementation of the Connections methods of the Management API.
+pub struct Connections {
+    pub(crate) api: ManagementApi,
+}
+
+impl Connections {
+    /// Retrieve the list of connections, implementation of [`/api/v2/connections`] endpoint.
+    ///
+    /// [`/api/v2/connections`]: https://auth0.com/docs/api/management/v2/connections/get-connections
+    pub fn list(&self) -> ListConnectionsBuilder {
+        let mut builder = ListConnectionsBuilder::default();
+        builder.api(self.api.clone());
+        builder
+    }
+
+    /// Retrieve connection details, implementation of [`/api/v2/connections/{id}`] endpoint.
+    ///
+    /// [`/api/v2/connections/{id}`]: https://auth0.com/docs/api/management/v2/connections/get-connections-by-id
+    pub fn get<T: Into<String>>(&self, id: T) -> GetConnectionBuilder {
+        let mut builder = GetConnectionBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Create a new connection with the given strategy, implementation of
+    /// [`/api/v2/connections`] endpoint.
+    ///
+    /// [`/api/v2/connections`]: https://auth0.com/docs/api/management/v2/connections/post-connections
+    pub fn create<T, U>(&self, name: T, strategy: U) -> CreateConnectionBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = CreateConnectionBuilder::default();
+        builder.api(self.api.clone()).name(name).strategy(strategy);
+        builder
+    }
+
+    /// Update a connection, implementation of [`/api/v2/connections/{id}`] endpoint.
+    ///
+    /// [`/api/v2/connections/{id}`]: https://auth0.com/docs/api/management/v2/connections/patch-connections-by-id
+    pub fn update<T: Into<String>>(&self, id: T) -> UpdateConnectionBuilder {
+        let mut builder = UpdateConnectionBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Delete a connection and all its users, implementation of [`/api/v2/connections/{id}`]
+    /// endpoint.
+    ///
+    /// [`/api/v2/connections/{id}`]: https://auth0.com/docs/api/management/v2/connections/delete-connections-by-id
+    pub fn delete<T: Into<String>>(&self, id: T) -> DeleteConnectionBuilder {
+        let mut builder = DeleteConnectionBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Check the status of an enterprise connection, implementation of
+    /// [`/api/v2/connections/{id}/status`] endpoint.
+    ///
+    /// The request succeeds if the connection is online, and fails with a `not_found` error
+    /// otherwise.
+    ///
+    /// [`/api/v2/connections/{id}/status`]: https://auth0.com/docs/api/management/v2/connections/get-status
+    pub fn status<T: Into<String>>(&self, id: T) -> GetConnectionStatusBuilder {
+        let mut builder = GetConnectionStatusBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Delete a user of a database connection by email, implementation of
+    /// [`/api/v2/connections/{id}/users`] endpoint.
+    ///
+    /// [`/api/v2/connections/{id}/users`]: https://auth0.com/docs/api/management/v2/connections/delete-users-by-email
+    pub fn delete_user<T, U>(&self, id: T, email: U) -> DeleteConnectionUserBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = DeleteConnectionUserBuilder::default();
+        builder.api(self.api.clone()).id(id).email(email);
+        builder
+    }
+}
+
+/// Retrieve connections matching provided filters.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListConnections {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// Page index of the results to return. First page is 0.
+    #[builder(setter(strip_option), default)]
+    page: Option<usize>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(setter(strip_option), default)]
+    per_page: Option<usize>,
+    /// Return results inside an object that contains the total result count. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    include_totals: Option<bool>,
+    /// Only retrieve connections with this strategy.
+    #[builder(setter(strip_option, into), default)]
+    strategy: Option<String>,
+    /// Only retrieve the connection with this name.
+    #[builder(setter(strip_option, into), default)]
+    name: Option<String>,
+    /// List of fields to include or exclude.
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
+    #[builder(setter(custom), default)]
+    fields: Vec<String>,
+    /// Whether specified fields are to be included.
+    #[builder(setter(strip_option), default)]
+    include_fields: Option<bool>,
+}
+
+/// Response for [`ListConnections`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListConnectionsResponse {
+    /// List of connections.
+    pub connections: Vec<models::Connection>,
+    /// Page offset.
+    pub start: Option<usize>,
+    /// Maximum number of items per page.
+    pub limit: Option<usize>,
+    /// Number of items per page.
+    pub length: Option<usize>,
+    /// Total number of elements.
+    pub total: Option<usize>,
+}
+
+impl ListConnections {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListConnectionsResponse> {
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(CONNECTIONS_ENDPOINT, self).await
+        } else {
+            let connections = self.api.http_get(CONNECTIONS_ENDPOINT, self).await?;
+            Ok(ListConnectionsResponse {
+                start: None,
+                limit: None,
+                length: None,
+                total: None,
+                connections,
+            })
+        }
+    }
+}
+
+impl Paginated for ListConnections {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListConnectionsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListConnectionsResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::Connection> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.connections,
+                total: response.total,
+            })
+        })
+    }
+
+    /// Append one element to the list of fields.
+    pub fn field<T: Into<String>>(&mut self, field: T) -> &mut Self {
+        self.fields.get_or_insert_with(Vec::new).push(field.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of fields.
+    pub fn fields<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.fields
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Retrieve connection details.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct GetConnection {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the connection to retrieve.
+    #[builder(private, setter(into))]
+    id: String,
+}
+
+/// Response for [`GetConnection`].
+pub type GetConnectionResponse = models::Connection;
+
+impl GetConnectionBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<GetConnectionResponse> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", CONNECTIONS_ENDPOINT, path_segment(&request.id));
+        request.api.http_get(&endpoint, &()).await
+    }
+}
+
+/// Create a new connection.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct CreateConnection {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// Name of the connection.
+    #[builder(private, setter(into))]
+    name: String,
+    /// Type of the connection, such as `auth0`, `google-oauth2` or `samlp`.
+    #[builder(private, setter(into))]
+    strategy: String,
+    /// Name of the connection displayed on the login page.
+    #[builder(setter(strip_option, into), default)]
+    display_name: Option<String>,
+    /// Options of the connection, that must match its strategy.
+    #[builder(setter(strip_option, into), default)]
+    options: Option<models::ConnectionOptions>,
+    /// IDs of the clients for which the connection is enabled.
+    #[builder(setter(custom), default)]
+    enabled_clients: Vec<String>,
+    /// Realms the connection belongs to, defaults to the name of the connection.
+    #[builder(setter(custom), default)]
+    realms: Vec<String>,
+    /// Whether the connection is a domain level connection, available to third party clients.
+    #[builder(setter(strip_option), default)]
+    is_domain_connection: Option<bool>,
+    /// Metadata associated with the connection.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[builder(setter(custom), default)]
+    metadata: HashMap<String, String>,
+}
+
+/// Response for [`CreateConnection`].
+pub type CreateConnectionResponse = models::Connection;
+
+impl CreateConnectionBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<CreateConnectionResponse> {
+        let request = self.build()?;
+        request.api.http_post(CONNECTIONS_ENDPOINT, &request).await
+    }
+
+    /// Append one element to the list of enabled clients.
+    pub fn enabled_client<T: Into<String>>(&mut self, client_id: T) -> &mut Self {
+        self.enabled_clients
+            .get_or_insert_with(Vec::new)
+            .push(client_id.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of enabled clients.
+    pub fn enabled_clients<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.enabled_clients
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    /// Append one element to the list of realms.
+    pub fn realm<T: Into<String>>(&mut self, realm: T) -> &mut Self {
+        self.realms.get_or_insert_with(Vec::new).push(realm.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of realms.
+    pub fn realms<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.realms
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add one entry to the metadata of the connection.
+    pub fn metadata<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Update a connection.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct UpdateConnection {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the connection to update.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Name of the connection displayed on the login page.
+    #[builder(setter(strip_option, into), default)]
+    display_name: Option<String>,
+    /// Options of the connection, replacing all the current options.
+    #[builder(setter(strip_option, into), default)]
+    options: Option<models::ConnectionOptions>,
+    /// IDs of the clients for which the connection is enabled, replacing the current ones.
+    #[builder(setter(custom), default)]
+    enabled_clients: Option<Vec<String>>,
+    /// Realms the connection belongs to, replacing the current ones.
+    #[builder(setter(custom), default)]
+    realms: Option<Vec<String>>,
+    /// Whether the connection is a domain level connection, available to third party clients.
+    #[builder(setter(strip_option), default)]
+    is_domain_connection: Option<bool>,
+    /// Metadata associated with the connection, replacing the current one.
+    #[builder(setter(custom), default)]
+    metadata: Option<HashMap<String, String>>,
+}
+
+/// Response for [`UpdateConnection`].
+pub type UpdateConnectionResponse = models::Connection;
+
+impl UpdateConnectionBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<UpdateConnectionResponse> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", CONNECTIONS_ENDPOINT, path_segment(&request.id));
+        request.api.http_patch(&endpoint, &request).await
+    }
+
+    /// Append one element to the list of enabled clients.
+    pub fn enabled_client<T: Into<String>>(&mut self, client_id: T) -> &mut Self {
+        self.enabled_clients
+            .get_or_insert(None)
+            .get_or_insert_with(Vec::new)
+            .push(client_id.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of enabled clients.
+    pub fn enabled_clients<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.enabled_clients
+            .get_or_insert(None)
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    /// Append one element to the list of realms.
+    pub fn realm<T: Into<String>>(&mut self, realm: T) -> &mut Self {
+        self.realms
+            .get_or_insert(None)
+            .get_or_insert_with(Vec::new)
+            .push(realm.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of realms.
+    pub fn realms<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.realms
+            .get_or_insert(None)
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add one entry to the metadata of the connection.
+    pub fn metadata<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.metadata
+            .get_or_insert(None)
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Delete a connection.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct DeleteConnection {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the connection to delete.
+    #[builder(private, setter(into))]
+    id: String,
+}
+
+impl DeleteConnectionBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", CONNECTIONS_ENDPOINT, path_segment(&request.id));
+        request.api.http_delete(&endpoint).await
+    }
+}
+
+/// Check the status of an enterprise connection.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct GetConnectionStatus {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the connection to check.
+    #[builder(private, setter(into))]
+    id: String,
+}
+
+impl GetConnectionStatusBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/status",
+            CONNECTIONS_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request.api.http_get_no_content(&endpoint).await
+    }
+}
+
+/// Delete a user of a database connection by email.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct DeleteConnectionUser {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the database connection.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Email address of the user to delete.
+    #[builder(private, setter(into))]
+    email: String,
+}
+
+impl DeleteConnectionUserBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/users",
+            CONNECTIONS_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request
+            .api
+            .http_delete_with_query(&endpoint, &request)
+            .await
+    }
+}
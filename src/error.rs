@@ -0,0 +1,317 @@
+//! Error types returned by the SDK.
+
+use std::collections::HashMap;
+
+use reqwest::{header::HeaderMap, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+/// Result type used throughout the SDK.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Errors returned by the SDK.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The HTTP request could not be sent or its response could not be read.
+    #[error("transport error: {0}")]
+    Transport(#[from] reqwest::Error),
+    /// The request could not be built because of a missing or invalid parameter.
+    #[error("invalid request: {0}")]
+    Builder(String),
+    /// The operation requires a client secret, but none was configured.
+    #[error("missing client_secret")]
+    MissingClientSecret,
+    /// An URL could not be parsed or joined.
+    #[error("invalid url: {0}")]
+    Url(#[from] url::ParseError),
+    /// The parameters of an URL could not be encoded.
+    #[error("failed to encode url parameters: {0}")]
+    UrlEncode(#[from] serde_urlencoded::ser::Error),
+    /// An HTTP header could not be built from the given value.
+    #[error("invalid header value: {0}")]
+    Header(#[from] reqwest::header::InvalidHeaderValue),
+    /// The response body could not be deserialized.
+    #[error("failed to deserialize response: {0}")]
+    Deserialize(#[from] serde_json::Error),
+    /// A token could not be decoded or its signature or standard claims are invalid.
+    #[error("invalid token: {0}")]
+    Jwt(#[from] jsonwebtoken::errors::Error),
+    /// A token failed the validation of its claims.
+    #[error("invalid token: {0}")]
+    InvalidToken(String),
+    /// The device code or the backchannel authentication request expired while polling for the
+    /// token.
+    #[error("the authorization request expired")]
+    Expired,
+    /// Auth0 answered with an error status code.
+    #[error(transparent)]
+    Api(Box<ApiError>),
+}
+
+impl Error {
+    /// Returns the API error, if this error was returned by Auth0.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Self::Api(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the Auth0 error code, if this error was returned by Auth0.
+    pub fn code(&self) -> Option<&ErrorCode> {
+        self.api_error().map(|err| &err.code)
+    }
+
+    /// Returns the MFA token, if this error is a `mfa_required` error returned by Auth0.
+    pub fn mfa_token(&self) -> Option<&str> {
+        self.api_error().and_then(|err| err.payload.mfa_token())
+    }
+}
+
+impl From<ApiError> for Error {
+    fn from(err: ApiError) -> Self {
+        Self::Api(Box::new(err))
+    }
+}
+
+impl From<derive_builder::UninitializedFieldError> for Error {
+    fn from(err: derive_builder::UninitializedFieldError) -> Self {
+        Self::Builder(err.to_string())
+    }
+}
+
+/// An error response returned by Auth0.
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    /// HTTP status code of the response.
+    pub status: StatusCode,
+    /// HTTP headers of the response.
+    pub headers: HeaderMap,
+    /// The parsed error code.
+    pub code: ErrorCode,
+    /// The error payload as returned by Auth0.
+    pub payload: ApiErrorPayload,
+}
+
+impl ApiError {
+    /// Build an error from the parts of an error response.
+    pub(crate) fn new(status: StatusCode, headers: HeaderMap, body: &[u8]) -> Self {
+        let payload: ApiErrorPayload =
+            serde_json::from_slice(body).unwrap_or_else(|_| ApiErrorPayload {
+                message: Some(String::from_utf8_lossy(body).into_owned())
+                    .filter(|message| !message.is_empty()),
+                ..Default::default()
+            });
+        // The Management API puts the HTTP reason phrase in `error` and the actual code, when
+        // there is one, in `errorCode`. The database connections endpoints use `code` instead.
+        let code = match (&payload.error_code, &payload.error, payload.status_code) {
+            (Some(code), _, _) => ErrorCode::from(code.as_str()),
+            (None, Some(code), None) => ErrorCode::from(code.as_str()),
+            _ => match &payload.code {
+                Some(code) => ErrorCode::from(code.as_str()),
+                None => ErrorCode::from_status(status),
+            },
+        };
+        Self {
+            status,
+            headers,
+            code,
+            payload,
+        }
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.payload.description() {
+            Some(description) => write!(f, "{} ({}): {}", self.code, self.status, description),
+            None => write!(f, "{} ({})", self.code, self.status),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// The body of an error response.
+///
+/// The Authentication API uses the `error` and `error_description` fields, while the Management
+/// API uses `statusCode`, `error`, `message` and `errorCode`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ApiErrorPayload {
+    /// Error code or, for the Management API, the HTTP reason phrase.
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Human readable description of the error.
+    #[serde(default)]
+    pub error_description: Option<String>,
+    /// Human readable message of the error.
+    #[serde(default)]
+    pub message: Option<String>,
+    /// HTTP status code of the response.
+    #[serde(rename = "statusCode", default)]
+    pub status_code: Option<u16>,
+    /// Error code returned by the Management API.
+    #[serde(rename = "errorCode", default)]
+    pub error_code: Option<String>,
+    /// Error code returned by the database connections endpoints.
+    #[serde(default)]
+    pub code: Option<String>,
+    /// Other values.
+    #[serde(flatten, default)]
+    pub values: HashMap<String, JsonValue>,
+}
+
+impl ApiErrorPayload {
+    /// Returns the human readable description of the error, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.error_description
+            .as_deref()
+            .or(self.message.as_deref())
+    }
+
+    /// Returns the token used to continue a login that requires multi-factor authentication.
+    pub fn mfa_token(&self) -> Option<&str> {
+        self.values.get("mfa_token").and_then(JsonValue::as_str)
+    }
+}
+
+/// Error codes returned by Auth0.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// `invalid_request`
+    InvalidRequest,
+    /// `invalid_client`
+    InvalidClient,
+    /// `invalid_grant`
+    InvalidGrant,
+    /// `invalid_scope`
+    InvalidScope,
+    /// `unauthorized_client`
+    UnauthorizedClient,
+    /// `unsupported_grant_type`
+    UnsupportedGrantType,
+    /// `unsupported_token_type`
+    UnsupportedTokenType,
+    /// `invalid_target`
+    InvalidTarget,
+    /// `access_denied`
+    AccessDenied,
+    /// `mfa_required`
+    MfaRequired,
+    /// `authorization_pending`
+    AuthorizationPending,
+    /// `slow_down`
+    SlowDown,
+    /// `expired_token`
+    ExpiredToken,
+    /// `invalid_body`
+    InvalidBody,
+    /// `invalid_query_string`
+    InvalidQueryString,
+    /// `invalid_uri`
+    InvalidUri,
+    /// `unauthorized`
+    Unauthorized,
+    /// `insufficient_scope`
+    InsufficientScope,
+    /// `inexistent_user`
+    InexistentUser,
+    /// `inexistent_client`
+    InexistentClient,
+    /// `not_found`
+    NotFound,
+    /// `conflict`
+    Conflict,
+    /// `too_many_requests`
+    TooManyRequests,
+    /// `server_error`
+    ServerError,
+    /// Any other error code.
+    Other(String),
+}
+
+impl ErrorCode {
+    /// Returns the error code as sent by Auth0.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::InvalidRequest => "invalid_request",
+            Self::InvalidClient => "invalid_client",
+            Self::InvalidGrant => "invalid_grant",
+            Self::InvalidScope => "invalid_scope",
+            Self::UnauthorizedClient => "unauthorized_client",
+            Self::UnsupportedGrantType => "unsupported_grant_type",
+            Self::UnsupportedTokenType => "unsupported_token_type",
+            Self::InvalidTarget => "invalid_target",
+            Self::AccessDenied => "access_denied",
+            Self::MfaRequired => "mfa_required",
+            Self::AuthorizationPending => "authorization_pending",
+            Self::SlowDown => "slow_down",
+            Self::ExpiredToken => "expired_token",
+            Self::InvalidBody => "invalid_body",
+            Self::InvalidQueryString => "invalid_query_string",
+            Self::InvalidUri => "invalid_uri",
+            Self::Unauthorized => "unauthorized",
+            Self::InsufficientScope => "insufficient_scope",
+            Self::InexistentUser => "inexistent_user",
+            Self::InexistentClient => "inexistent_client",
+            Self::NotFound => "not_found",
+            Self::Conflict => "conflict",
+            Self::TooManyRequests => "too_many_requests",
+            Self::ServerError => "server_error",
+            Self::Other(code) => code,
+        }
+    }
+
+    /// Infer the error code from the HTTP status code, used when the body carries none.
+    fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::BAD_REQUEST => Self::InvalidRequest,
+            StatusCode::UNAUTHORIZED => Self::Unauthorized,
+            StatusCode::FORBIDDEN => Self::InsufficientScope,
+            StatusCode::NOT_FOUND => Self::NotFound,
+            StatusCode::CONFLICT => Self::Conflict,
+            StatusCode::TOO_MANY_REQUESTS => Self::TooManyRequests,
+            status if status.is_server_error() => Self::ServerError,
+            status => Self::Other(status.as_u16().to_string()),
+        }
+    }
+}
+
+impl From<&str> for ErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "invalid_request" => Self::InvalidRequest,
+            "invalid_client" => Self::InvalidClient,
+            "invalid_grant" => Self::InvalidGrant,
+            "invalid_scope" => Self::InvalidScope,
+            "unauthorized_client" => Self::UnauthorizedClient,
+            "unsupported_grant_type" => Self::UnsupportedGrantType,
+            "unsupported_token_type" => Self::UnsupportedTokenType,
+            "invalid_target" => Self::InvalidTarget,
+            "access_denied" => Self::AccessDenied,
+            "mfa_required" => Self::MfaRequired,
+            "authorization_pending" => Self::AuthorizationPending,
+            "slow_down" => Self::SlowDown,
+            "expired_token" => Self::ExpiredToken,
+            "invalid_body" => Self::InvalidBody,
+            "invalid_query_string" => Self::InvalidQueryString,
+            "invalid_uri" => Self::InvalidUri,
+            "unauthorized" => Self::Unauthorized,
+            "insufficient_scope" => Self::InsufficientScope,
+            "inexistent_user" => Self::InexistentUser,
+            "inexistent_client" => Self::InexistentClient,
+            "not_found" => Self::NotFound,
+            "conflict" => Self::Conflict,
+            "too_many_requests" => Self::TooManyRequests,
+            "server_error" => Self::ServerError,
+            code => Self::Other(code.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,142 @@
+//! Helpers shared by the API clients to send requests and handle HTTP responses.
+
+use std::sync::{Mutex, PoisonError, RwLock};
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, PercentEncode, CONTROLS};
+use reqwest::{Client, IntoUrl, Method, Request, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    error::ApiError,
+    retry::{RateLimit, RetryPolicy},
+    Result,
+};
+
+/// Characters encoded in a path segment, the same as [`url::Url::path_segments_mut`] plus the `|`
+/// separating the connection from the ID in Auth0 user IDs.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'%')
+    .add(b'|');
+
+/// Percent-encode a value to use it as a single segment of an endpoint's path.
+pub(crate) fn path_segment(value: &str) -> PercentEncode<'_> {
+    utf8_percent_encode(value, PATH_SEGMENT)
+}
+
+/// HTTP client applying the retry policy and tracking the rate limit state.
+#[derive(Debug, Default)]
+pub(crate) struct HttpClient {
+    /// HTTP client.
+    client: Client,
+    /// Policy used to retry failed requests.
+    retry_policy: RwLock<RetryPolicy>,
+    /// Last rate limit state reported by Auth0.
+    rate_limit: Mutex<Option<RateLimit>>,
+}
+
+impl HttpClient {
+    /// Start building a request.
+    pub(crate) fn request<U: IntoUrl>(&self, method: Method, url: U) -> RequestBuilder {
+        self.client.request(method, url)
+    }
+
+    /// Start building a POST request.
+    pub(crate) fn post<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.post(url)
+    }
+
+    /// Returns the retry policy.
+    pub(crate) fn retry_policy(&self) -> RetryPolicy {
+        *self
+            .retry_policy
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Replace the retry policy.
+    pub(crate) fn set_retry_policy(&self, policy: RetryPolicy) {
+        *self
+            .retry_policy
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = policy;
+    }
+
+    /// Returns the last rate limit state reported by Auth0.
+    pub(crate) fn rate_limit(&self) -> Option<RateLimit> {
+        *self
+            .rate_limit
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Send the request, retrying it according to the retry policy.
+    pub(crate) async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        self.send_with_policy(request, self.retry_policy()).await
+    }
+
+    /// Send the request, retrying it according to the given policy.
+    pub(crate) async fn send_with_policy(
+        &self,
+        request: RequestBuilder,
+        policy: RetryPolicy,
+    ) -> Result<Response> {
+        let request = request.build()?;
+        let mut attempt = 1;
+        loop {
+            // Requests with a streaming body cannot be cloned, and thus cannot be retried.
+            let Some(current) = request.try_clone() else {
+                return self.execute(request).await;
+            };
+            let response = self.execute(current).await?;
+            if !policy.should_retry(attempt, request.method(), response.status()) {
+                return Ok(response);
+            }
+            tokio::time::sleep(policy.delay(attempt, response.status(), response.headers())).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send the request once, recording the rate limit state of the response.
+    async fn execute(&self, request: Request) -> Result<Response> {
+        let response = self.client.execute(request).await?;
+        if let Some(rate_limit) = RateLimit::from_headers(response.headers()) {
+            *self
+                .rate_limit
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(rate_limit);
+        }
+        Ok(response)
+    }
+}
+
+/// Check the status of the response and deserialize its JSON body.
+pub(crate) async fn json_response<T: DeserializeOwned>(response: Response) -> Result<T> {
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = response.bytes().await?;
+    if !status.is_success() {
+        return Err(ApiError::new(status, headers, &body).into());
+    }
+    serde_json::from_slice(&body).map_err(Into::into)
+}
+
+/// Check the status of the response, discarding its body.
+pub(crate) async fn empty_response(response: Response) -> Result<()> {
+    let status = response.status();
+    if !status.is_success() {
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        return Err(ApiError::new(status, headers, &body).into());
+    }
+    Ok(())
+}
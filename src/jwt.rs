@@ -0,0 +1,337 @@
+//! Validation of the JSON Web Tokens issued by Auth0, and signing of the client assertions.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use derive_builder::Builder;
+use jsonwebtoken::{
+    errors::ErrorKind,
+    jwk::{Jwk, JwkSet},
+    DecodingKey, EncodingKey, Header, Validation,
+};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::{formats::PreferOne, serde_as, OneOrMany};
+use time::OffsetDateTime;
+use tokio::sync::RwLock;
+
+use crate::{AuthenticationApi, Error, Result};
+
+#[doc(no_inline)]
+pub use jsonwebtoken::Algorithm;
+
+/// Endpoint serving the JSON Web Key Set of the tenant.
+pub(crate) const JWKS_ENDPOINT: &str = "/.well-known/jwks.json";
+
+/// Minimum time between two refreshes of the key set triggered by an unknown key ID.
+const JWKS_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Default clock skew tolerated when validating time based claims, in seconds.
+const DEFAULT_LEEWAY: u64 = 60;
+
+/// Type of the client assertions, sent along with them.
+pub(crate) const CLIENT_ASSERTION_TYPE: &str =
+    "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// Lifetime of the client assertions, in seconds.
+const CLIENT_ASSERTION_LIFETIME: i64 = 60;
+
+/// Claims of a token issued by Auth0.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Claims {
+    /// Issuer of the token.
+    pub iss: String,
+    /// Subject of the token, usually the ID of the user.
+    pub sub: String,
+    /// Audiences of the token.
+    #[serde_as(as = "OneOrMany<_, PreferOne>")]
+    pub aud: Vec<String>,
+    /// Expiration time, in seconds since the Unix epoch.
+    pub exp: i64,
+    /// Issue time, in seconds since the Unix epoch.
+    #[serde(default)]
+    pub iat: Option<i64>,
+    /// Time before which the token must not be accepted, in seconds since the Unix epoch.
+    #[serde(default)]
+    pub nbf: Option<i64>,
+    /// Authorized party, the client the token was issued to.
+    #[serde(default)]
+    pub azp: Option<String>,
+    /// Value used to associate a client session with an ID token.
+    #[serde(default)]
+    pub nonce: Option<String>,
+    /// Time when the user authenticated, in seconds since the Unix epoch.
+    #[serde(default)]
+    pub auth_time: Option<i64>,
+    /// Scopes granted to the access token, separated by a space.
+    #[serde(default)]
+    pub scope: Option<String>,
+    /// Permissions granted to the access token, when RBAC is enabled.
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    /// Other values.
+    #[serde(flatten, default)]
+    pub values: HashMap<String, JsonValue>,
+}
+
+/// Cache of the JSON Web Key Set of the tenant.
+#[derive(Debug, Default)]
+pub(crate) struct JwksCache {
+    /// State of the cache, the lock is held while the key set is refreshed.
+    state: RwLock<JwksState>,
+}
+
+/// State of the [`JwksCache`].
+#[derive(Debug, Default)]
+struct JwksState {
+    /// Cached keys, indexed by key ID.
+    keys: HashMap<String, Jwk>,
+    /// Instant of the last refresh, if any.
+    refreshed_at: Option<Instant>,
+}
+
+impl JwksCache {
+    /// Returns the key with the given ID, refreshing the key set if the key is unknown.
+    async fn key(&self, api: &AuthenticationApi, kid: &str) -> Result<Jwk> {
+        if let Some(jwk) = self.state.read().await.keys.get(kid) {
+            return Ok(jwk.clone());
+        }
+        let mut state = self.state.write().await;
+        // The key set may have been refreshed while waiting for the lock.
+        if let Some(jwk) = state.keys.get(kid) {
+            return Ok(jwk.clone());
+        }
+        if state
+            .refreshed_at
+            .is_none_or(|refreshed_at| refreshed_at.elapsed() >= JWKS_MIN_REFRESH_INTERVAL)
+        {
+            let jwks: JwkSet = api.http_get(JWKS_ENDPOINT).await?;
+            state.keys = jwks
+                .keys
+                .into_iter()
+                .filter_map(|jwk| Some((jwk.common.key_id.clone()?, jwk)))
+                .collect();
+            state.refreshed_at = Some(Instant::now());
+        }
+        state
+            .keys
+            .get(kid)
+            .cloned()
+            .ok_or_else(|| Error::InvalidToken(format!("unknown signing key `{kid}`")))
+    }
+}
+
+/// Verify the signature and the claims of a token.
+async fn verify(
+    api: &AuthenticationApi,
+    token: &str,
+    audience: &str,
+    algorithms: &[Algorithm],
+    leeway: u64,
+) -> Result<Claims> {
+    let header = jsonwebtoken::decode_header(token)?;
+    if !algorithms.contains(&header.alg) {
+        return Err(Error::InvalidToken(format!(
+            "unexpected signing algorithm {:?}",
+            header.alg
+        )));
+    }
+    let key = match header.alg {
+        Algorithm::HS256 => {
+            let secret = api.client_secret().ok_or(Error::MissingClientSecret)?;
+            DecodingKey::from_secret(secret.as_bytes())
+        }
+        _ => {
+            let kid = header
+                .kid
+                .ok_or_else(|| Error::InvalidToken("missing key ID".to_string()))?;
+            DecodingKey::from_jwk(&api.jwks().key(api, &kid).await?)?
+        }
+    };
+    let issuer = api.issuer().await?;
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[issuer]);
+    validation.set_audience(&[audience]);
+    validation.set_required_spec_claims(&["iss", "sub", "aud", "exp"]);
+    validation.validate_nbf = true;
+    validation.leeway = leeway;
+    Ok(jsonwebtoken::decode(token, &key, &validation)?.claims)
+}
+
+/// Verify an ID token.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct VerifyIdToken {
+    #[builder(private)]
+    api: AuthenticationApi,
+    /// The token to verify.
+    #[builder(private, setter(into))]
+    token: String,
+    /// The nonce sent with the authorization request, that must match the one of the token.
+    #[builder(setter(strip_option, into), default)]
+    nonce: Option<String>,
+    /// The maximum authentication age sent with the authorization request, the user must have
+    /// authenticated within this duration.
+    #[builder(setter(strip_option), default)]
+    max_age: Option<Duration>,
+    /// Clock skew tolerated when validating time based claims, in seconds. Default is 60.
+    #[builder(default = "DEFAULT_LEEWAY")]
+    leeway: u64,
+    /// Accepted signing algorithms. Default is `RS256`, tokens signed with the client secret
+    /// must be allowed explicitly with `HS256`.
+    #[builder(default = "vec![Algorithm::RS256]")]
+    algorithms: Vec<Algorithm>,
+}
+
+impl VerifyIdTokenBuilder {
+    /// Create a new builder for the given token.
+    pub(crate) fn new(api: AuthenticationApi, token: String) -> Self {
+        let mut builder = Self::default();
+        builder.api(api).token(token);
+        builder
+    }
+
+    /// Verify the token, returning its claims.
+    pub async fn verify(&self) -> Result<Claims> {
+        let request = self.build()?;
+        let client_id = request.api.client_id();
+        let claims = verify(
+            &request.api,
+            &request.token,
+            client_id,
+            &request.algorithms,
+            request.leeway,
+        )
+        .await?;
+        if claims.aud.len() > 1 && claims.azp.is_none() {
+            return Err(Error::InvalidToken(
+                "missing authorized party with multiple audiences".to_string(),
+            ));
+        }
+        if claims.azp.as_deref().is_some_and(|azp| azp != client_id) {
+            return Err(Error::InvalidToken("authorized party mismatch".to_string()));
+        }
+        if let Some(nonce) = &request.nonce {
+            if claims.nonce.as_ref() != Some(nonce) {
+                return Err(Error::InvalidToken("nonce mismatch".to_string()));
+            }
+        }
+        if let Some(max_age) = request.max_age {
+            let auth_time = claims
+                .auth_time
+                .ok_or_else(|| Error::InvalidToken("missing authentication time".to_string()))?;
+            let now = OffsetDateTime::now_utc().unix_timestamp();
+            let deadline = i64::try_from(max_age.as_secs().saturating_add(request.leeway))
+                .ok()
+                .and_then(|secs| auth_time.checked_add(secs))
+                .ok_or_else(|| Error::InvalidToken("invalid authentication time".to_string()))?;
+            if now > deadline {
+                return Err(Error::InvalidToken(
+                    "authentication time is too far in the past".to_string(),
+                ));
+            }
+        }
+        Ok(claims)
+    }
+}
+
+/// Verify an access token issued for an API.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct VerifyAccessToken {
+    #[builder(private)]
+    api: AuthenticationApi,
+    /// The token to verify.
+    #[builder(private, setter(into))]
+    token: String,
+    /// The identifier of the API the token must be issued for.
+    #[builder(private, setter(into))]
+    audience: String,
+    /// Clock skew tolerated when validating time based claims, in seconds. Default is 60.
+    #[builder(default = "DEFAULT_LEEWAY")]
+    leeway: u64,
+    /// Accepted signing algorithms. Default is `RS256` and `PS256`.
+    #[builder(default = "vec![Algorithm::RS256, Algorithm::PS256]")]
+    algorithms: Vec<Algorithm>,
+}
+
+impl VerifyAccessTokenBuilder {
+    /// Create a new builder for the given token and audience.
+    pub(crate) fn new(api: AuthenticationApi, token: String, audience: String) -> Self {
+        let mut builder = Self::default();
+        builder.api(api).token(token).audience(audience);
+        builder
+    }
+
+    /// Verify the token, returning its claims.
+    pub async fn verify(&self) -> Result<Claims> {
+        let request = self.build()?;
+        verify(
+            &request.api,
+            &request.token,
+            &request.audience,
+            &request.algorithms,
+            request.leeway,
+        )
+        .await
+    }
+}
+
+/// Signer of the JSON Web Tokens used by the application to authenticate with a private key.
+pub(crate) struct ClientAssertionSigner {
+    /// The private key.
+    key: EncodingKey,
+    /// Header of the assertions, with the algorithm and the key ID.
+    header: Header,
+}
+
+/// Claims of a client assertion.
+#[derive(Serialize)]
+struct ClientAssertionClaims<'a> {
+    /// Issuer of the assertion, the Client ID.
+    iss: &'a str,
+    /// Subject of the assertion, the Client ID.
+    sub: &'a str,
+    /// Audience of the assertion, the tenant's domain.
+    aud: &'a str,
+    /// Issue time, in seconds since the Unix epoch.
+    iat: i64,
+    /// Expiration time, in seconds since the Unix epoch.
+    exp: i64,
+    /// Unique identifier of the assertion, preventing its replay.
+    jti: String,
+}
+
+impl ClientAssertionSigner {
+    /// Create a signer from a PEM encoded RSA private key.
+    pub(crate) fn new(pem_key: &[u8], kid: String, alg: Algorithm) -> Result<Self> {
+        if !matches!(alg, Algorithm::RS256 | Algorithm::RS384 | Algorithm::PS256) {
+            return Err(jsonwebtoken::errors::Error::from(ErrorKind::InvalidAlgorithm).into());
+        }
+        let key = EncodingKey::from_rsa_pem(pem_key)?;
+        let mut header = Header::new(alg);
+        header.kid = Some(kid);
+        Ok(Self { key, header })
+    }
+
+    /// Sign a new assertion for the given client, to be sent to the given audience.
+    pub(crate) fn sign(&self, client_id: &str, audience: &str) -> Result<String> {
+        let mut jti = [0; 16];
+        rand::thread_rng().fill_bytes(&mut jti);
+        let iat = OffsetDateTime::now_utc().unix_timestamp();
+        let claims = ClientAssertionClaims {
+            iss: client_id,
+            sub: client_id,
+            aud: audience,
+            iat,
+            exp: iat + CLIENT_ASSERTION_LIFETIME,
+            jti: URL_SAFE_NO_PAD.encode(jti),
+        };
+        Ok(jsonwebtoken::encode(&self.header, &claims, &self.key)?)
+    }
+}
@@ -1,8 +1,25 @@
 #[doc(inline)]
-pub use self::{auth::AuthenticationApi, clients::Clients, mgmt::ManagementApi, users::Users};
+pub use self::{
+    auth::AuthenticationApi,
+    clients::Clients,
+    connections::Connections,
+    error::{Error, Result},
+    mgmt::ManagementApi,
+    organizations::Organizations,
+    roles::Roles,
+    users::Users,
+};
 
 pub mod auth;
 pub mod clients;
+pub mod connections;
+pub mod error;
+mod http;
+pub mod jwt;
 mod mgmt;
 pub mod models;
+pub mod organizations;
+pub mod pagination;
+pub mod retry;
+pub mod roles;
 pub mod users;
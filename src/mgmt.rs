@@ -1,13 +1,20 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use anyhow::{Context, Result};
 use reqwest::{
     header::{self, HeaderValue},
-    Client, Url,
+    Method, RequestBuilder, Url,
 };
 use serde::{de::DeserializeOwned, Serialize};
+use tokio::{sync::Mutex, time::Instant};
 
-use crate::{Clients, Users};
+use crate::{
+    http::{self, HttpClient},
+    retry::{RateLimit, RetryPolicy},
+    AuthenticationApi, Clients, Connections, Organizations, Result, Roles, Users,
+};
+
+/// Time before expiration at which a cached token is renewed.
+const TOKEN_RENEWAL_LEEWAY: Duration = Duration::from_secs(60);
 
 /// Implementation of the management API.
 #[derive(Clone)]
@@ -17,9 +24,62 @@ struct Inner {
     /// The tenant's domain.
     domain: Url,
     /// The token to authenticate the calls with.
-    api_token: HeaderValue,
+    api_token: ApiToken,
     /// Default HTTP client.
-    client: Client,
+    client: HttpClient,
+}
+
+/// Source of the token used to authenticate the calls.
+enum ApiToken {
+    /// A token given by the user, used as is.
+    Static(HeaderValue),
+    /// A token obtained with the client credentials flow, renewed before it expires.
+    ClientCredentials {
+        /// The authentication API used to request new tokens.
+        auth: AuthenticationApi,
+        /// The audience of the requested tokens.
+        audience: String,
+        /// The last token obtained, if any. The lock is held while a new token is requested, so
+        /// that concurrent calls share the same request.
+        cached: Mutex<Option<CachedToken>>,
+    },
+}
+
+/// A token obtained from the authentication API.
+struct CachedToken {
+    /// The value of the authorization header.
+    header: HeaderValue,
+    /// Instant after which the token must be renewed.
+    renew_at: Instant,
+}
+
+impl ApiToken {
+    /// Returns the value of the authorization header, requesting a new token if needed.
+    async fn header(&self) -> Result<HeaderValue> {
+        match self {
+            Self::Static(header) => Ok(header.clone()),
+            Self::ClientCredentials {
+                auth,
+                audience,
+                cached,
+            } => {
+                let mut cached = cached.lock().await;
+                if let Some(token) = cached.as_ref().filter(|t| Instant::now() < t.renew_at) {
+                    return Ok(token.header.clone());
+                }
+                let requested_at = Instant::now();
+                let token = auth.get_token(audience.as_str())?.send().await?;
+                let header = HeaderValue::from_str(&format!("Bearer {}", token.access_token))?;
+                let expires_in = Duration::from_secs(token.expires_in);
+                let renew_at = requested_at + expires_in.saturating_sub(TOKEN_RENEWAL_LEEWAY);
+                *cached = Some(CachedToken {
+                    header: header.clone(),
+                    renew_at,
+                });
+                Ok(header)
+            }
+        }
+    }
 }
 
 impl std::fmt::Debug for ManagementApi {
@@ -34,14 +94,56 @@ impl ManagementApi {
     /// Create a new istance of the management API.
     pub fn new(domain: &str, api_token: &str) -> Result<Self> {
         let inner = Inner {
-            domain: Url::parse(domain).context("Auth0 domain is not a valid url")?,
-            api_token: HeaderValue::from_str(&format!("Bearer {}", api_token))
-                .context("Auth0 token is not a valid string")?,
-            client: Client::new(),
+            domain: Url::parse(domain)?,
+            api_token: ApiToken::Static(HeaderValue::from_str(&format!("Bearer {}", api_token))?),
+            client: HttpClient::default(),
         };
         Ok(Self(Arc::new(inner)))
     }
 
+    /// Create a new istance of the management API, authenticated with tokens obtained through the
+    /// client credentials flow.
+    ///
+    /// The token is requested on the first call, cached, and renewed shortly before it expires.
+    /// The given authentication API must be configured with a client secret.
+    pub fn with_client_credentials<T: Into<String>>(
+        domain: &str,
+        auth: AuthenticationApi,
+        audience: T,
+    ) -> Result<Self> {
+        let audience = audience.into();
+        // Fail early if the client secret is missing.
+        auth.get_token(audience.as_str())?;
+        let inner = Inner {
+            domain: Url::parse(domain)?,
+            api_token: ApiToken::ClientCredentials {
+                auth,
+                audience,
+                cached: Mutex::new(None),
+            },
+            client: HttpClient::default(),
+        };
+        Ok(Self(Arc::new(inner)))
+    }
+
+    /// Set the policy used to retry failed requests.
+    ///
+    /// The policy is shared with all the clones of this instance.
+    pub fn with_retry_policy(self, policy: RetryPolicy) -> Self {
+        self.0.client.set_retry_policy(policy);
+        self
+    }
+
+    /// Returns the policy used to retry failed requests.
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.0.client.retry_policy()
+    }
+
+    /// Returns the last rate limit state reported by Auth0, if any.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.0.client.rate_limit()
+    }
+
     /// Contains all the methods to call the `/users` endpoints.
     pub fn users(&self) -> Users {
         let api = self.clone();
@@ -54,22 +156,126 @@ impl ManagementApi {
         Clients { api }
     }
 
+    /// Contains all the methods to call the `/roles` endpoints.
+    pub fn roles(&self) -> Roles {
+        let api = self.clone();
+        Roles { api }
+    }
+
+    /// Contains all the methods to call the `/connections` endpoints.
+    pub fn connections(&self) -> Connections {
+        let api = self.clone();
+        Connections { api }
+    }
+
+    /// Contains all the methods to call the `/organizations` endpoints.
+    pub fn organizations(&self) -> Organizations {
+        let api = self.clone();
+        Organizations { api }
+    }
+
+    /// Start building an authenticated request to the given endpoint.
+    async fn http_request(&self, method: Method, endpoint: &str) -> Result<RequestBuilder> {
+        Ok(self
+            .0
+            .client
+            .request(method, self.0.domain.join(endpoint)?)
+            .header(header::AUTHORIZATION, self.0.api_token.header().await?))
+    }
+
     /// Send a get request to the given endpoint.
     pub(crate) async fn http_get<Q, T>(&self, endpoint: &str, query: &Q) -> Result<T>
     where
         Q: Serialize,
         T: DeserializeOwned,
     {
-        self.0
-            .client
-            .get(self.0.domain.join(endpoint)?)
-            .query(query)
-            .header(header::AUTHORIZATION, self.0.api_token.clone())
-            .send()
+        let request = self.http_request(Method::GET, endpoint).await?.query(query);
+        let response = self.0.client.send(request).await?;
+        http::json_response(response).await
+    }
+
+    /// Send a get request to the given endpoint, discarding the response body.
+    pub(crate) async fn http_get_no_content(&self, endpoint: &str) -> Result<()> {
+        let request = self.http_request(Method::GET, endpoint).await?;
+        let response = self.0.client.send(request).await?;
+        http::empty_response(response).await
+    }
+
+    /// Send a post request to the given endpoint.
+    pub(crate) async fn http_post<B, T>(&self, endpoint: &str, json: &B) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        let request = self.http_request(Method::POST, endpoint).await?.json(json);
+        let response = self.0.client.send(request).await?;
+        http::json_response(response).await
+    }
+
+    /// Send a post request to the given endpoint, discarding the response body.
+    pub(crate) async fn http_post_no_content<B: Serialize>(
+        &self,
+        endpoint: &str,
+        json: &B,
+    ) -> Result<()> {
+        let request = self.http_request(Method::POST, endpoint).await?.json(json);
+        let response = self.0.client.send(request).await?;
+        http::empty_response(response).await
+    }
+
+    /// Send a post request without body to the given endpoint.
+    pub(crate) async fn http_post_empty<T>(&self, endpoint: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let request = self.http_request(Method::POST, endpoint).await?;
+        let response = self.0.client.send(request).await?;
+        http::json_response(response).await
+    }
+
+    /// Send a patch request to the given endpoint.
+    pub(crate) async fn http_patch<B, T>(&self, endpoint: &str, json: &B) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        let request = self.http_request(Method::PATCH, endpoint).await?.json(json);
+        let response = self.0.client.send(request).await?;
+        http::json_response(response).await
+    }
+
+    /// Send a delete request to the given endpoint.
+    pub(crate) async fn http_delete(&self, endpoint: &str) -> Result<()> {
+        let request = self.http_request(Method::DELETE, endpoint).await?;
+        let response = self.0.client.send(request).await?;
+        http::empty_response(response).await
+    }
+
+    /// Send a delete request with query parameters to the given endpoint.
+    pub(crate) async fn http_delete_with_query<Q: Serialize>(
+        &self,
+        endpoint: &str,
+        query: &Q,
+    ) -> Result<()> {
+        let request = self
+            .http_request(Method::DELETE, endpoint)
+            .await?
+            .query(query);
+        let response = self.0.client.send(request).await?;
+        http::empty_response(response).await
+    }
+
+    /// Send a delete request with a body to the given endpoint.
+    pub(crate) async fn http_delete_with_body<B: Serialize>(
+        &self,
+        endpoint: &str,
+        json: &B,
+    ) -> Result<()> {
+        let request = self
+            .http_request(Method::DELETE, endpoint)
             .await?
-            .error_for_status()?
-            .json()
-            .await
-            .map_err(Into::into)
+            .json(json);
+        let response = self.0.client.send(request).await?;
+        http::empty_response(response).await
     }
 }
@@ -15,4 +15,7 @@ pub struct AccessToken {
     /// The user's ID Token.
     #[serde(default)]
     pub id_token: Option<String>,
+    /// The type of the issued token, returned by the token exchange grant.
+    #[serde(default)]
+    pub issued_token_type: Option<String>,
 }
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// The response of a backchannel authentication request.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BackchannelAuthorization {
+    /// The ID of the request, used to poll the token endpoint.
+    pub auth_req_id: String,
+    /// The lifetime (in seconds) of the request.
+    pub expires_in: u64,
+    /// The interval (in seconds) at which the app should poll the token URL to request a token.
+    pub interval: u64,
+}
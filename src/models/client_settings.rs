@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use super::{EncryptionKey, JwtConfiguration, OidcLogoutConfig};
+
+/// Settings of a client, used to create or update it.
+///
+/// Only the fields that are set are sent, when updating a client the other fields are left
+/// untouched.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none", default)],
+)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ClientSettings {
+    /// Name of this client.
+    pub name: Option<String>,
+    /// Free text description of this client.
+    pub description: Option<String>,
+    /// Type of client used to determine which settings are applicable.
+    pub app_type: Option<String>,
+    /// URL of the logo to display for this client.
+    pub logo_uri: Option<String>,
+    /// Whether this client a first party client.
+    pub is_first_party: Option<bool>,
+    /// Whether this client conforms to strict OIDC specifications.
+    pub oidc_conformant: Option<bool>,
+    /// List of URLs whitelisted for Auth0 to use as a callback to the client after authentication.
+    pub callbacks: Option<Vec<String>>,
+    /// List of URLs allowed to make requests from JavaScript to Auth0 API (typically used with
+    /// CORS).
+    pub allowed_origins: Option<Vec<String>>,
+    /// List of allowed origins for use with Cross-Origin Authentication, Device Flow, and web
+    /// message response mode.
+    pub web_origins: Option<Vec<String>>,
+    /// List of audiences/realms for SAML protocol.
+    pub client_aliases: Option<Vec<String>>,
+    /// List of allow clients and API ids that are allowed to make delegation requests. Empty means
+    /// all all your clients are allowed.
+    pub allowed_clients: Option<Vec<String>>,
+    /// List of URLs that are valid to redirect to after logout from Auth0. Wildcards are allowed
+    /// for subdomains.
+    pub allowed_logout_urls: Option<Vec<String>>,
+    /// Configuration for OIDC backchannel logout.
+    pub oidc_logout: Option<OidcLogoutConfig>,
+    /// List of grant types supported for this application.
+    pub grant_types: Option<Vec<String>>,
+    /// Configuration related to JWTs for the client.
+    pub jwt_configuration: Option<JwtConfiguration>,
+    /// Encryption used for WsFed responses with this client.
+    pub encryption_key: Option<EncryptionKey>,
+    /// Applies only to SSO clients and determines whether Auth0 will handle Single Sign On (true)
+    /// or whether the Identity Provider will (false).
+    pub sso: Option<bool>,
+    /// Whether Single Sign On is disabled.
+    pub sso_disabled: Option<bool>,
+    /// Whether this client can be used to make cross-origin authentication requests.
+    pub cross_origin_authentication: Option<bool>,
+    /// URL of the location in your site where the cross origin verification takes place for the
+    /// cross-origin auth flow when performing Auth in your own domain instead of Auth0 hosted
+    /// login page.
+    pub cross_origin_loc: Option<String>,
+    /// Whether a custom login page is to be used (true) or the default provided login page
+    /// (false).
+    pub custom_login_page_on: Option<bool>,
+    /// The content (HTML, CSS, JS) of the custom login page.
+    pub custom_login_page: Option<String>,
+    /// The content (HTML, CSS, JS) of the custom login page. (Used on Previews)
+    pub custom_login_page_preview: Option<String>,
+    /// HTML form template to be used for WS-Federation.
+    pub form_template: Option<String>,
+    /// Addons enabled for this client and their associated configurations.
+    pub addons: Option<JsonValue>,
+    /// Defines the requested authentication method for the token endpoint.
+    pub token_endpoint_auth_method: Option<String>,
+    /// Metadata associated with the client.
+    pub client_metadata: Option<JsonValue>,
+    /// Additional configuration for native mobile apps.
+    pub mobile: Option<JsonValue>,
+    /// Initiate login uri, must be https.
+    pub initiate_login_uri: Option<String>,
+    /// Configure native social settings.
+    pub native_social_login: Option<JsonValue>,
+    /// Refresh token configuration.
+    pub refresh_token: Option<JsonValue>,
+    /// Defines how to proceed during an authentication transaction with regards an organization.
+    pub organization_usage: Option<String>,
+    /// Defines how to proceed during an authentication transaction when `organization_usage` is
+    /// `require`.
+    pub organization_require_behavior: Option<String>,
+    /// Defines client authentication methods.
+    pub client_authentication_methods: Option<JsonValue>,
+    /// Makes the use of Pushed Authorization Requests mandatory for this client.
+    pub require_pushed_authorization_requests: Option<bool>,
+    /// Custom configuration for Access Tokens.
+    pub access_token: Option<JsonValue>,
+    /// JWT-secured Authorization Requests (JAR) settings.
+    pub signed_request_object: Option<JsonValue>,
+    /// Defines the compliance level for this client, which may restrict it's capabilities.
+    pub compliance_level: Option<String>,
+}
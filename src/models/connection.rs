@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use super::ConnectionOptions;
+
+/// Represents a connection, a source of users such as a database or an identity provider.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(from = "RawConnection")]
+pub struct Connection {
+    /// ID of the connection.
+    pub id: String,
+    /// Name of the connection.
+    pub name: String,
+    /// Name of the connection displayed on the login page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Type of the connection, such as `auth0`, `google-oauth2` or `samlp`.
+    pub strategy: String,
+    /// Options of the connection, depending on its strategy.
+    pub options: ConnectionOptions,
+    /// IDs of the clients for which the connection is enabled.
+    pub enabled_clients: Vec<String>,
+    /// Realms the connection belongs to, used to select it in the resource owner password flow.
+    pub realms: Vec<String>,
+    /// Whether the connection is a domain level connection, available to third party clients.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_domain_connection: Option<bool>,
+    /// Metadata associated with the connection.
+    pub metadata: HashMap<String, String>,
+}
+
+/// A connection as returned by Auth0, with its options not yet parsed according to its strategy.
+#[derive(Deserialize)]
+struct RawConnection {
+    id: String,
+    name: String,
+    #[serde(default)]
+    display_name: Option<String>,
+    strategy: String,
+    #[serde(default)]
+    options: JsonValue,
+    #[serde(default)]
+    enabled_clients: Vec<String>,
+    #[serde(default)]
+    realms: Vec<String>,
+    #[serde(default)]
+    is_domain_connection: Option<bool>,
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+}
+
+impl From<RawConnection> for Connection {
+    fn from(raw: RawConnection) -> Self {
+        Self {
+            options: ConnectionOptions::from_strategy(&raw.strategy, raw.options),
+            id: raw.id,
+            name: raw.name,
+            display_name: raw.display_name,
+            strategy: raw.strategy,
+            enabled_clients: raw.enabled_clients,
+            realms: raw.realms,
+            is_domain_connection: raw.is_domain_connection,
+            metadata: raw.metadata,
+        }
+    }
+}
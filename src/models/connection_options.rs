@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+/// Options of a connection, depending on its strategy.
+///
+/// The options of the strategies without a dedicated variant, or that could not be parsed, are
+/// kept as raw JSON.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum ConnectionOptions {
+    /// Options of a database connection, strategy `auth0`.
+    Database(DatabaseConnectionOptions),
+    /// Options of a Google connection, strategy `google-oauth2`.
+    GoogleOAuth2(GoogleOAuth2ConnectionOptions),
+    /// Options of a SAML connection, strategy `samlp`.
+    Saml(SamlConnectionOptions),
+    /// Options of a Microsoft Entra ID (Azure AD) connection, strategy `waad`.
+    AzureAd(AzureAdConnectionOptions),
+    /// Options of an OpenID Connect connection, strategy `oidc`.
+    Oidc(OidcConnectionOptions),
+    /// Options of any other strategy.
+    Other(JsonValue),
+}
+
+impl ConnectionOptions {
+    /// Parse the raw options of a connection according to its strategy.
+    pub fn from_strategy(strategy: &str, options: JsonValue) -> Self {
+        fn parse<T, F>(options: JsonValue, variant: F) -> ConnectionOptions
+        where
+            T: serde::de::DeserializeOwned,
+            F: FnOnce(T) -> ConnectionOptions,
+        {
+            match serde_json::from_value(options.clone()) {
+                Ok(options) => variant(options),
+                Err(_) => ConnectionOptions::Other(options),
+            }
+        }
+
+        match strategy {
+            "auth0" => parse(options, Self::Database),
+            "google-oauth2" => parse(options, Self::GoogleOAuth2),
+            "samlp" => parse(options, Self::Saml),
+            "waad" => parse(options, Self::AzureAd),
+            "oidc" => parse(options, Self::Oidc),
+            _ => Self::Other(options),
+        }
+    }
+}
+
+impl From<DatabaseConnectionOptions> for ConnectionOptions {
+    fn from(options: DatabaseConnectionOptions) -> Self {
+        Self::Database(options)
+    }
+}
+
+impl From<GoogleOAuth2ConnectionOptions> for ConnectionOptions {
+    fn from(options: GoogleOAuth2ConnectionOptions) -> Self {
+        Self::GoogleOAuth2(options)
+    }
+}
+
+impl From<SamlConnectionOptions> for ConnectionOptions {
+    fn from(options: SamlConnectionOptions) -> Self {
+        Self::Saml(options)
+    }
+}
+
+impl From<AzureAdConnectionOptions> for ConnectionOptions {
+    fn from(options: AzureAdConnectionOptions) -> Self {
+        Self::AzureAd(options)
+    }
+}
+
+impl From<OidcConnectionOptions> for ConnectionOptions {
+    fn from(options: OidcConnectionOptions) -> Self {
+        Self::Oidc(options)
+    }
+}
+
+impl From<JsonValue> for ConnectionOptions {
+    fn from(options: JsonValue) -> Self {
+        Self::Other(options)
+    }
+}
+
+impl Default for ConnectionOptions {
+    fn default() -> Self {
+        Self::Other(JsonValue::Object(Default::default()))
+    }
+}
+
+/// Options of a database connection.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none", default)],
+)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct DatabaseConnectionOptions {
+    /// Password strength level, one of `none`, `low`, `fair`, `good` or `excellent`.
+    #[serde(rename = "passwordPolicy")]
+    pub password_policy: Option<String>,
+    /// Whether users must provide a username in addition to their email address.
+    pub requires_username: Option<bool>,
+    /// Whether the brute force protection is enabled.
+    pub brute_force_protection: Option<bool>,
+    /// Whether the signup of new users is disabled.
+    pub disable_signup: Option<bool>,
+    /// Whether users are imported from a legacy database on their first login.
+    pub import_mode: Option<bool>,
+    /// Whether the custom database scripts are used instead of the Auth0 user store.
+    pub enabled_database_customization: Option<bool>,
+    /// Custom database scripts, indexed by action.
+    #[serde(rename = "customScripts")]
+    pub custom_scripts: Option<HashMap<String, String>>,
+    /// Other options.
+    #[serde(flatten, default)]
+    pub values: HashMap<String, JsonValue>,
+}
+
+/// Options of a Google connection.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none", default)],
+)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct GoogleOAuth2ConnectionOptions {
+    /// Client ID of the Google application, the Auth0 development keys are used when not set.
+    pub client_id: Option<String>,
+    /// Client secret of the Google application.
+    pub client_secret: Option<String>,
+    /// Client IDs of the other applications whose Google tokens are accepted.
+    pub allowed_audiences: Option<Vec<String>>,
+    /// Scopes requested to Google.
+    pub scope: Option<Vec<String>>,
+    /// Other options, such as the flags of the requested user attributes.
+    #[serde(flatten, default)]
+    pub values: HashMap<String, JsonValue>,
+}
+
+/// Options of a SAML connection.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none", default)],
+)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SamlConnectionOptions {
+    /// URL of the single sign-on endpoint of the identity provider.
+    #[serde(rename = "signInEndpoint")]
+    pub sign_in_endpoint: Option<String>,
+    /// URL of the single logout endpoint of the identity provider.
+    #[serde(rename = "signOutEndpoint")]
+    pub sign_out_endpoint: Option<String>,
+    /// Signing certificate of the identity provider, base64 encoded.
+    #[serde(rename = "signingCert")]
+    pub signing_cert: Option<String>,
+    /// Whether the authentication requests are signed.
+    #[serde(rename = "signSAMLRequest")]
+    pub sign_saml_request: Option<bool>,
+    /// Algorithm used to sign the requests, `rsa-sha1` or `rsa-sha256`.
+    #[serde(rename = "signatureAlgorithm")]
+    pub signature_algorithm: Option<String>,
+    /// Algorithm used to compute the digests, `sha1` or `sha256`.
+    #[serde(rename = "digestAlgorithm")]
+    pub digest_algorithm: Option<String>,
+    /// Binding used to send the authentication requests.
+    #[serde(rename = "protocolBinding")]
+    pub protocol_binding: Option<String>,
+    /// Attribute of the SAML assertion holding the user ID.
+    pub user_id_attribute: Option<String>,
+    /// Email domains of the users authenticating with this connection.
+    pub domain_aliases: Option<Vec<String>>,
+    /// Primary domain of the users authenticating with this connection.
+    pub tenant_domain: Option<String>,
+    /// Other options.
+    #[serde(flatten, default)]
+    pub values: HashMap<String, JsonValue>,
+}
+
+/// Options of a Microsoft Entra ID (Azure AD) connection.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none", default)],
+)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AzureAdConnectionOptions {
+    /// Client ID of the application registered in Microsoft Entra ID.
+    pub client_id: Option<String>,
+    /// Client secret of the application registered in Microsoft Entra ID.
+    pub client_secret: Option<String>,
+    /// Domain of the Microsoft Entra ID tenant.
+    pub domain: Option<String>,
+    /// Primary domain of the users authenticating with this connection.
+    pub tenant_domain: Option<String>,
+    /// Email domains of the users authenticating with this connection.
+    pub domain_aliases: Option<Vec<String>>,
+    /// Protocol used to communicate with Microsoft Entra ID, `openid-connect` or `ws-federation`.
+    pub waad_protocol: Option<String>,
+    /// Other options.
+    #[serde(flatten, default)]
+    pub values: HashMap<String, JsonValue>,
+}
+
+/// Options of an OpenID Connect connection.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none", default)],
+)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct OidcConnectionOptions {
+    /// Client ID of the application registered with the identity provider.
+    pub client_id: Option<String>,
+    /// Client secret of the application registered with the identity provider.
+    pub client_secret: Option<String>,
+    /// Flow used with the identity provider, `front_channel` or `back_channel`.
+    #[serde(rename = "type")]
+    pub flow: Option<String>,
+    /// URL of the discovery document of the identity provider.
+    pub discovery_url: Option<String>,
+    /// Issuer of the tokens of the identity provider.
+    pub issuer: Option<String>,
+    /// URL of the authorization endpoint of the identity provider.
+    pub authorization_endpoint: Option<String>,
+    /// URL of the token endpoint of the identity provider.
+    pub token_endpoint: Option<String>,
+    /// URL of the user info endpoint of the identity provider.
+    pub userinfo_endpoint: Option<String>,
+    /// URL of the JSON Web Key Set of the identity provider.
+    pub jwks_uri: Option<String>,
+    /// Scopes requested to the identity provider, separated by a space.
+    pub scope: Option<String>,
+    /// Email domains of the users authenticating with this connection.
+    pub domain_aliases: Option<Vec<String>>,
+    /// Primary domain of the users authenticating with this connection.
+    pub tenant_domain: Option<String>,
+    /// Other options.
+    #[serde(flatten, default)]
+    pub values: HashMap<String, JsonValue>,
+}
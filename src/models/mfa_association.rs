@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// The response of the enrollment of a multi-factor authenticator.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MfaAssociation {
+    /// The type of the enrolled authenticator, `otp` or `oob`.
+    pub authenticator_type: String,
+    /// The secret to configure `otp` authenticators.
+    #[serde(default)]
+    pub secret: Option<String>,
+    /// The URI to generate a QR code for `otp` or `auth0` authenticators.
+    #[serde(default)]
+    pub barcode_uri: Option<String>,
+    /// The channel of `oob` authenticators.
+    #[serde(default)]
+    pub oob_channel: Option<String>,
+    /// The code to send with the `mfa-oob` grant to confirm the enrollment.
+    #[serde(default)]
+    pub oob_code: Option<String>,
+    /// How the user binds the challenge, `prompt` when a code must be entered.
+    #[serde(default)]
+    pub binding_method: Option<String>,
+    /// The recovery codes, returned on the first enrollment.
+    #[serde(default)]
+    pub recovery_codes: Vec<String>,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// A multi-factor authenticator of the user.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MfaAuthenticator {
+    /// The ID of the authenticator.
+    pub id: String,
+    /// The type of the authenticator, such as `otp`, `oob` or `recovery-code`.
+    pub authenticator_type: String,
+    /// Whether the authenticator has been confirmed.
+    pub active: bool,
+    /// The channel of `oob` authenticators.
+    #[serde(default)]
+    pub oob_channel: Option<String>,
+    /// The name of the authenticator, such as the masked phone number.
+    #[serde(default)]
+    pub name: Option<String>,
+}
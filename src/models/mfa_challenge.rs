@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// The challenge issued for a multi-factor authenticator.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MfaChallenge {
+    /// The type of the challenge, `otp` or `oob`.
+    pub challenge_type: String,
+    /// The code to send with the `mfa-oob` grant, for `oob` challenges.
+    #[serde(default)]
+    pub oob_code: Option<String>,
+    /// How the user binds the challenge, `prompt` when a code must be entered.
+    #[serde(default)]
+    pub binding_method: Option<String>,
+}
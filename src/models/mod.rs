@@ -1,18 +1,63 @@
 #[doc(inline)]
 pub use self::{
-    access_token::AccessToken, client::Client, device_code::DeviceCode,
-    encryption_key::EncryptionKey, identity::Identity, jwt_configuration::JwtConfiguration,
-    oidc_logout_config::OidcLogoutConfig, profile_data::ProfileData, signing_keys::SigningKey,
+    access_token::AccessToken,
+    backchannel_authorization::BackchannelAuthorization,
+    client::Client,
+    client_settings::ClientSettings,
+    connection::Connection,
+    connection_options::{
+        AzureAdConnectionOptions, ConnectionOptions, DatabaseConnectionOptions,
+        GoogleOAuth2ConnectionOptions, OidcConnectionOptions, SamlConnectionOptions,
+    },
+    device_code::DeviceCode,
+    encryption_key::EncryptionKey,
+    identity::Identity,
+    jwt_configuration::JwtConfiguration,
+    mfa_association::MfaAssociation,
+    mfa_authenticator::MfaAuthenticator,
+    mfa_challenge::MfaChallenge,
+    oidc_logout_config::OidcLogoutConfig,
+    open_id_configuration::OpenIdConfiguration,
+    organization::{Organization, OrganizationBranding, OrganizationBrandingColors},
+    organization_connection::{OrganizationConnection, OrganizationConnectionDetails},
+    organization_invitation::{OrganizationInvitation, OrganizationInvitee, OrganizationInviter},
+    organization_member::OrganizationMember,
+    passwordless_start::PasswordlessStart,
+    permission::{Permission, PermissionSource},
+    profile_data::ProfileData,
+    pushed_authorization_request::PushedAuthorizationRequest,
+    role::Role,
+    signing_keys::SigningKey,
+    signup_user::SignupUser,
     user::User,
+    user_info::UserInfo,
 };
 
 mod access_token;
+mod backchannel_authorization;
 mod client;
+mod client_settings;
+mod connection;
+mod connection_options;
 mod device_code;
 mod encryption_key;
 mod identity;
 mod jwt_configuration;
+mod mfa_association;
+mod mfa_authenticator;
+mod mfa_challenge;
 mod oidc_logout_config;
+mod open_id_configuration;
+mod organization;
+mod organization_connection;
+mod organization_invitation;
+mod organization_member;
+mod passwordless_start;
+mod permission;
 mod profile_data;
+mod pushed_authorization_request;
+mod role;
 mod signing_keys;
+mod signup_user;
 mod user;
+mod user_info;
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+/// The OpenID Connect discovery document of a tenant.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OpenIdConfiguration {
+    /// Issuer of the tokens.
+    pub issuer: String,
+    /// URL of the authorization endpoint.
+    pub authorization_endpoint: String,
+    /// URL of the token endpoint.
+    pub token_endpoint: String,
+    /// URL of the JSON Web Key Set used to sign the tokens.
+    pub jwks_uri: String,
+    /// URL of the device authorization endpoint.
+    #[serde(default)]
+    pub device_authorization_endpoint: Option<String>,
+    /// URL of the user info endpoint.
+    #[serde(default)]
+    pub userinfo_endpoint: Option<String>,
+    /// URL of the MFA challenge endpoint.
+    #[serde(default)]
+    pub mfa_challenge_endpoint: Option<String>,
+    /// URL of the dynamic client registration endpoint.
+    #[serde(default)]
+    pub registration_endpoint: Option<String>,
+    /// URL of the token revocation endpoint.
+    #[serde(default)]
+    pub revocation_endpoint: Option<String>,
+    /// URL of the logout endpoint.
+    #[serde(default)]
+    pub end_session_endpoint: Option<String>,
+    /// URL of the pushed authorization request endpoint.
+    #[serde(default)]
+    pub pushed_authorization_request_endpoint: Option<String>,
+    /// URL of the client initiated backchannel authentication endpoint.
+    #[serde(default)]
+    pub backchannel_authentication_endpoint: Option<String>,
+    /// Supported scopes.
+    #[serde(default)]
+    pub scopes_supported: Vec<String>,
+    /// Supported response types.
+    #[serde(default)]
+    pub response_types_supported: Vec<String>,
+    /// Supported response modes.
+    #[serde(default)]
+    pub response_modes_supported: Vec<String>,
+    /// Supported subject identifier types.
+    #[serde(default)]
+    pub subject_types_supported: Vec<String>,
+    /// Supported algorithms to sign the ID tokens.
+    #[serde(default)]
+    pub id_token_signing_alg_values_supported: Vec<String>,
+    /// Supported client authentication methods of the token endpoint.
+    #[serde(default)]
+    pub token_endpoint_auth_methods_supported: Vec<String>,
+    /// Supported claims.
+    #[serde(default)]
+    pub claims_supported: Vec<String>,
+    /// Supported PKCE code challenge methods.
+    #[serde(default)]
+    pub code_challenge_methods_supported: Vec<String>,
+    /// Other values.
+    #[serde(flatten, default)]
+    pub values: HashMap<String, JsonValue>,
+}
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Represents an organization, grouping the users of a business customer.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Organization {
+    /// ID of the organization.
+    pub id: String,
+    /// Name of the organization, used in the authorization requests.
+    pub name: String,
+    /// Friendly name of the organization.
+    #[serde(default)]
+    pub display_name: Option<String>,
+    /// Branding of the login pages of the organization.
+    #[serde(default)]
+    pub branding: Option<OrganizationBranding>,
+    /// Metadata associated with the organization.
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+/// Branding of the login pages of an organization.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct OrganizationBranding {
+    /// URL of the logo of the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// Colors of the login pages of the organization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colors: Option<OrganizationBrandingColors>,
+}
+
+/// Colors of the login pages of an organization.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrganizationBrandingColors {
+    /// HEX color code of the primary elements, such as the buttons.
+    pub primary: String,
+    /// HEX color code of the page background.
+    pub page_background: String,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a connection enabled for an organization.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrganizationConnection {
+    /// ID of the connection.
+    pub connection_id: String,
+    /// Whether users logging in with the connection are automatically added as members of the
+    /// organization.
+    #[serde(default)]
+    pub assign_membership_on_login: bool,
+    /// Whether the connection is displayed as a button on the login page of the organization.
+    #[serde(default)]
+    pub show_as_button: Option<bool>,
+    /// Name and strategy of the connection.
+    #[serde(default)]
+    pub connection: Option<OrganizationConnectionDetails>,
+}
+
+/// Name and strategy of a connection enabled for an organization.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrganizationConnectionDetails {
+    /// Name of the connection.
+    pub name: String,
+    /// Strategy of the connection.
+    pub strategy: String,
+}
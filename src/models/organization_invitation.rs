@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use time::OffsetDateTime;
+
+/// Represents an invitation to join an organization.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrganizationInvitation {
+    /// ID of the invitation.
+    pub id: String,
+    /// ID of the organization.
+    pub organization_id: String,
+    /// Person sending the invitation.
+    pub inviter: OrganizationInviter,
+    /// Person receiving the invitation.
+    pub invitee: OrganizationInvitee,
+    /// URL of the invitation, accepting it.
+    #[serde(default)]
+    pub invitation_url: Option<String>,
+    /// Date and time the invitation was created.
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub created_at: Option<OffsetDateTime>,
+    /// Date and time the invitation expires.
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub expires_at: Option<OffsetDateTime>,
+    /// ID of the client the invitee is redirected to once the invitation is accepted.
+    pub client_id: String,
+    /// ID of the connection the invitee must use to authenticate.
+    #[serde(default)]
+    pub connection_id: Option<String>,
+    /// Data of the user to which the invitee has read-only access.
+    #[serde(default)]
+    pub app_metadata: Option<JsonValue>,
+    /// Data of the user to which the invitee has read/write access.
+    #[serde(default)]
+    pub user_metadata: Option<JsonValue>,
+    /// IDs of the roles assigned to the invitee once the invitation is accepted.
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// ID of the invitation ticket.
+    #[serde(default)]
+    pub ticket_id: Option<String>,
+}
+
+/// Person sending an invitation to join an organization.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrganizationInviter {
+    /// Name of the inviter, displayed in the invitation email.
+    pub name: String,
+}
+
+/// Person receiving an invitation to join an organization.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrganizationInvitee {
+    /// Email address the invitation is sent to.
+    pub email: String,
+}
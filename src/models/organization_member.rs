@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use super::Role;
+
+/// Represents a member of an organization.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrganizationMember {
+    /// ID of the user.
+    pub user_id: String,
+    /// Email address of the user.
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Name of the user.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// URL of the picture of the user.
+    #[serde(default)]
+    pub picture: Option<String>,
+    /// Roles of the user in the organization, only returned when requested with the `roles`
+    /// field.
+    #[serde(default)]
+    pub roles: Vec<Role>,
+}
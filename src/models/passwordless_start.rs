@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// The response of a passwordless authentication start.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PasswordlessStart {
+    /// Identifier of the request.
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// The email address the code or the link was sent to.
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Whether the email address is verified.
+    #[serde(default)]
+    pub email_verified: Option<bool>,
+    /// The phone number the code was sent to.
+    #[serde(default)]
+    pub phone_number: Option<String>,
+    /// Whether the phone number is verified.
+    #[serde(default)]
+    pub phone_verified: Option<bool>,
+}
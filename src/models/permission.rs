@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a permission, defined by an API (resource server).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Permission {
+    /// Identifier of the API (resource server) defining the permission.
+    pub resource_server_identifier: String,
+    /// Name of the permission.
+    pub permission_name: String,
+    /// Name of the API (resource server) defining the permission.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_server_name: Option<String>,
+    /// Description of the permission.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Sources of a permission granted to a user, either directly or through roles.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<PermissionSource>,
+}
+
+/// Source of a permission granted to a user.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PermissionSource {
+    /// ID of the source, the ID of the role for permissions granted through a role.
+    #[serde(default)]
+    pub source_id: Option<String>,
+    /// Name of the source, the name of the role for permissions granted through a role.
+    #[serde(default)]
+    pub source_name: Option<String>,
+    /// Type of the source, `DIRECT` or `ROLE`.
+    pub source_type: String,
+}
+
+impl Permission {
+    /// Create a reference to the given permission of an API, as expected by the endpoints
+    /// assigning or removing permissions.
+    pub fn new<T, U>(resource_server_identifier: T, permission_name: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        Self {
+            resource_server_identifier: resource_server_identifier.into(),
+            permission_name: permission_name.into(),
+            resource_server_name: None,
+            description: None,
+            sources: Vec::new(),
+        }
+    }
+}
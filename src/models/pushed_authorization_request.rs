@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// The reference to an authorization request pushed to Auth0.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PushedAuthorizationRequest {
+    /// The URI referencing the request, to send to the authorization endpoint.
+    pub request_uri: String,
+    /// The lifetime (in seconds) of the `request_uri`.
+    pub expires_in: u64,
+}
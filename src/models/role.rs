@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a role, a collection of permissions that can be assigned to users.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Role {
+    /// ID of the role.
+    pub id: String,
+    /// Name of the role.
+    pub name: String,
+    /// Description of the role.
+    #[serde(default)]
+    pub description: Option<String>,
+}
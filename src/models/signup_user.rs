@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+/// A user created in a database connection through the signup endpoint.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SignupUser {
+    /// The user's identifier, without the connection prefix.
+    #[serde(rename = "_id", alias = "id")]
+    pub id: String,
+    /// The user's email address.
+    pub email: String,
+    /// Whether this email address is verified.
+    #[serde(default)]
+    pub email_verified: bool,
+    /// The user's username.
+    #[serde(default)]
+    pub username: Option<String>,
+    /// The user's given name.
+    #[serde(default)]
+    pub given_name: Option<String>,
+    /// The user's family name.
+    #[serde(default)]
+    pub family_name: Option<String>,
+    /// The user's full name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The user's nickname.
+    #[serde(default)]
+    pub nickname: Option<String>,
+    /// A URI pointing to the user's picture.
+    #[serde(default)]
+    pub picture: Option<String>,
+    /// The user metadata associated with the user.
+    #[serde(default)]
+    pub user_metadata: Option<JsonValue>,
+}
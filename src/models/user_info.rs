@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::{serde_as, PickFirst, TimestampSeconds};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// Standard OpenID Connect claims of the user, as returned by the `/userinfo` endpoint.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UserInfo {
+    /// Identifier of the user.
+    pub sub: String,
+    /// Full name of this user.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Given name/first name/forename of this user.
+    #[serde(default)]
+    pub given_name: Option<String>,
+    /// Family name/last name/surname of this user.
+    #[serde(default)]
+    pub family_name: Option<String>,
+    /// Middle name of this user.
+    #[serde(default)]
+    pub middle_name: Option<String>,
+    /// Preferred nickname or alias of this user.
+    #[serde(default)]
+    pub nickname: Option<String>,
+    /// Shorthand name by which the user wishes to be referred to.
+    #[serde(default)]
+    pub preferred_username: Option<String>,
+    /// URL to the profile page of this user.
+    #[serde(default)]
+    pub profile: Option<String>,
+    /// URL to picture, photo, or avatar of this user.
+    #[serde(default)]
+    pub picture: Option<String>,
+    /// URL to the web page or blog of this user.
+    #[serde(default)]
+    pub website: Option<String>,
+    /// Email address of this user.
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Whether this email address is verified.
+    #[serde(default)]
+    pub email_verified: Option<bool>,
+    /// Gender of this user.
+    #[serde(default)]
+    pub gender: Option<String>,
+    /// Birthday of this user.
+    #[serde(default)]
+    pub birthdate: Option<String>,
+    /// Time zone of this user.
+    #[serde(default)]
+    pub zoneinfo: Option<String>,
+    /// Locale of this user.
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// Phone number of this user.
+    #[serde(default)]
+    pub phone_number: Option<String>,
+    /// Whether this phone number is verified.
+    #[serde(default)]
+    pub phone_number_verified: Option<bool>,
+    /// Postal address of this user.
+    #[serde(default)]
+    pub address: Option<JsonValue>,
+    /// Date and time when this user was last updated/modified.
+    ///
+    /// Auth0 sends an RFC 3339 string, while OpenID Connect defines a number of seconds since the
+    /// epoch, both are accepted.
+    #[serde_as(as = "Option<PickFirst<(Rfc3339, TimestampSeconds<i64>)>>")]
+    #[serde(default)]
+    pub updated_at: Option<OffsetDateTime>,
+    /// Other values, such as namespaced custom claims.
+    #[serde(flatten, default)]
+    pub values: HashMap<String, JsonValue>,
+}
@@ -0,0 +1,1362 @@
+//! Implementation of the Organizations methods of the Management API.
+
+use std::collections::HashMap;
+
+use derive_builder::Builder;
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::{formats::CommaSeparator, serde_as, StringWithSeparator};
+
+use crate::{
+    http::path_segment,
+    models,
+    pagination::{
+        Checkpoint, CheckpointStream, Page, PageStream, Paginated, Pagination, DEFAULT_PER_PAGE,
+    },
+    ManagementApi, Result,
+};
+
+const ORGANIZATIONS_ENDPOINT: &str = "/api/v2/organizations";
+
+/// This struct provides an implementation of the Organizations methods of the Management API.
+pub struct Organizations {
+    pub(crate) api: ManagementApi,
+}
+
+impl Organizations {
+    /// Retrieve the list of organizations, implementation of [`/api/v2/organizations`] endpoint.
+    ///
+    /// [`/api/v2/organizations`]: https://auth0.com/docs/api/management/v2/organizations/get-organizations
+    pub fn list(&self) -> ListOrganizationsBuilder {
+        let mut builder = ListOrganizationsBuilder::default();
+        builder.api(self.api.clone());
+        builder
+    }
+
+    /// Retrieve organization details, implementation of [`/api/v2/organizations/{id}`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}`]: https://auth0.com/docs/api/management/v2/organizations/get-organizations-by-id
+    pub fn get<T: Into<String>>(&self, id: T) -> GetOrganizationBuilder {
+        let mut builder = GetOrganizationBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Retrieve organization details by name, implementation of
+    /// [`/api/v2/organizations/name/{name}`] endpoint.
+    ///
+    /// [`/api/v2/organizations/name/{name}`]: https://auth0.com/docs/api/management/v2/organizations/get-name-by-name
+    pub fn get_by_name<T: Into<String>>(&self, name: T) -> GetOrganizationByNameBuilder {
+        let mut builder = GetOrganizationByNameBuilder::default();
+        builder.api(self.api.clone()).name(name);
+        builder
+    }
+
+    /// Create a new organization, implementation of [`/api/v2/organizations`] endpoint.
+    ///
+    /// [`/api/v2/organizations`]: https://auth0.com/docs/api/management/v2/organizations/post-organizations
+    pub fn create<T: Into<String>>(&self, name: T) -> CreateOrganizationBuilder {
+        let mut builder = CreateOrganizationBuilder::default();
+        builder.api(self.api.clone()).name(name);
+        builder
+    }
+
+    /// Update an organization, implementation of [`/api/v2/organizations/{id}`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}`]: https://auth0.com/docs/api/management/v2/organizations/patch-organizations-by-id
+    pub fn update<T: Into<String>>(&self, id: T) -> UpdateOrganizationBuilder {
+        let mut builder = UpdateOrganizationBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Delete an organization, implementation of [`/api/v2/organizations/{id}`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}`]: https://auth0.com/docs/api/management/v2/organizations/delete-organizations-by-id
+    pub fn delete<T: Into<String>>(&self, id: T) -> DeleteOrganizationBuilder {
+        let mut builder = DeleteOrganizationBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Retrieve the members of an organization, implementation of
+    /// [`/api/v2/organizations/{id}/members`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/members`]: https://auth0.com/docs/api/management/v2/organizations/get-members
+    pub fn members<T: Into<String>>(&self, id: T) -> ListOrganizationMembersBuilder {
+        let mut builder = ListOrganizationMembersBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Add members to an organization, implementation of [`/api/v2/organizations/{id}/members`]
+    /// endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/members`]: https://auth0.com/docs/api/management/v2/organizations/post-members
+    pub fn add_members<T: Into<String>>(&self, id: T) -> AddOrganizationMembersBuilder {
+        let mut builder = AddOrganizationMembersBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Remove members from an organization, implementation of
+    /// [`/api/v2/organizations/{id}/members`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/members`]: https://auth0.com/docs/api/management/v2/organizations/delete-members
+    pub fn remove_members<T: Into<String>>(&self, id: T) -> RemoveOrganizationMembersBuilder {
+        let mut builder = RemoveOrganizationMembersBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Retrieve the roles of a member of an organization, implementation of
+    /// [`/api/v2/organizations/{id}/members/{user_id}/roles`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/members/{user_id}/roles`]: https://auth0.com/docs/api/management/v2/organizations/get-organization-member-roles
+    pub fn member_roles<T, U>(&self, id: T, user_id: U) -> ListOrganizationMemberRolesBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = ListOrganizationMemberRolesBuilder::default();
+        builder.api(self.api.clone()).id(id).user_id(user_id);
+        builder
+    }
+
+    /// Assign roles to a member of an organization, implementation of
+    /// [`/api/v2/organizations/{id}/members/{user_id}/roles`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/members/{user_id}/roles`]: https://auth0.com/docs/api/management/v2/organizations/post-organization-member-roles
+    pub fn assign_member_roles<T, U>(
+        &self,
+        id: T,
+        user_id: U,
+    ) -> AssignOrganizationMemberRolesBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = AssignOrganizationMemberRolesBuilder::default();
+        builder.api(self.api.clone()).id(id).user_id(user_id);
+        builder
+    }
+
+    /// Remove roles from a member of an organization, implementation of
+    /// [`/api/v2/organizations/{id}/members/{user_id}/roles`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/members/{user_id}/roles`]: https://auth0.com/docs/api/management/v2/organizations/delete-organization-member-roles
+    pub fn remove_member_roles<T, U>(
+        &self,
+        id: T,
+        user_id: U,
+    ) -> RemoveOrganizationMemberRolesBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = RemoveOrganizationMemberRolesBuilder::default();
+        builder.api(self.api.clone()).id(id).user_id(user_id);
+        builder
+    }
+
+    /// Retrieve the connections enabled for an organization, implementation of
+    /// [`/api/v2/organizations/{id}/enabled_connections`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/enabled_connections`]: https://auth0.com/docs/api/management/v2/organizations/get-enabled-connections
+    pub fn connections<T: Into<String>>(&self, id: T) -> ListOrganizationConnectionsBuilder {
+        let mut builder = ListOrganizationConnectionsBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Enable a connection for an organization, implementation of
+    /// [`/api/v2/organizations/{id}/enabled_connections`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/enabled_connections`]: https://auth0.com/docs/api/management/v2/organizations/post-enabled-connections
+    pub fn add_connection<T, U>(&self, id: T, connection_id: U) -> AddOrganizationConnectionBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = AddOrganizationConnectionBuilder::default();
+        builder
+            .api(self.api.clone())
+            .id(id)
+            .connection_id(connection_id);
+        builder
+    }
+
+    /// Update a connection enabled for an organization, implementation of
+    /// [`/api/v2/organizations/{id}/enabled_connections/{connection_id}`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/enabled_connections/{connection_id}`]: https://auth0.com/docs/api/management/v2/organizations/patch-enabled-connections-by-connection-id
+    pub fn update_connection<T, U>(
+        &self,
+        id: T,
+        connection_id: U,
+    ) -> UpdateOrganizationConnectionBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = UpdateOrganizationConnectionBuilder::default();
+        builder
+            .api(self.api.clone())
+            .id(id)
+            .connection_id(connection_id);
+        builder
+    }
+
+    /// Disable a connection for an organization, implementation of
+    /// [`/api/v2/organizations/{id}/enabled_connections/{connection_id}`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/enabled_connections/{connection_id}`]: https://auth0.com/docs/api/management/v2/organizations/delete-enabled-connections-by-connection-id
+    pub fn remove_connection<T, U>(
+        &self,
+        id: T,
+        connection_id: U,
+    ) -> RemoveOrganizationConnectionBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = RemoveOrganizationConnectionBuilder::default();
+        builder
+            .api(self.api.clone())
+            .id(id)
+            .connection_id(connection_id);
+        builder
+    }
+
+    /// Retrieve the invitations of an organization, implementation of
+    /// [`/api/v2/organizations/{id}/invitations`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/invitations`]: https://auth0.com/docs/api/management/v2/organizations/get-invitations
+    pub fn invitations<T: Into<String>>(&self, id: T) -> ListOrganizationInvitationsBuilder {
+        let mut builder = ListOrganizationInvitationsBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Invite a user to join an organization, implementation of
+    /// [`/api/v2/organizations/{id}/invitations`] endpoint.
+    ///
+    /// The invitee is redirected to the login route of the given client once the invitation is
+    /// accepted.
+    ///
+    /// [`/api/v2/organizations/{id}/invitations`]: https://auth0.com/docs/api/management/v2/organizations/post-invitations
+    pub fn create_invitation<T, U, V, W>(
+        &self,
+        id: T,
+        inviter_name: U,
+        invitee_email: V,
+        client_id: W,
+    ) -> CreateOrganizationInvitationBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+        V: Into<String>,
+        W: Into<String>,
+    {
+        let mut builder = CreateOrganizationInvitationBuilder::default();
+        builder
+            .api(self.api.clone())
+            .id(id)
+            .inviter(models::OrganizationInviter {
+                name: inviter_name.into(),
+            })
+            .invitee(models::OrganizationInvitee {
+                email: invitee_email.into(),
+            })
+            .client_id(client_id);
+        builder
+    }
+
+    /// Delete an invitation to join an organization, implementation of
+    /// [`/api/v2/organizations/{id}/invitations/{invitation_id}`] endpoint.
+    ///
+    /// [`/api/v2/organizations/{id}/invitations/{invitation_id}`]: https://auth0.com/docs/api/management/v2/organizations/delete-invitations-by-invitation-id
+    pub fn delete_invitation<T, U>(
+        &self,
+        id: T,
+        invitation_id: U,
+    ) -> DeleteOrganizationInvitationBuilder
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        let mut builder = DeleteOrganizationInvitationBuilder::default();
+        builder
+            .api(self.api.clone())
+            .id(id)
+            .invitation_id(invitation_id);
+        builder
+    }
+}
+
+/// Retrieve the list of organizations.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListOrganizations {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// Page index of the results to return. First page is 0.
+    #[builder(setter(strip_option), default)]
+    page: Option<usize>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(setter(strip_option), default)]
+    per_page: Option<usize>,
+    /// Return results inside an object that contains the total result count. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    include_totals: Option<bool>,
+}
+
+/// Response for [`ListOrganizations`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListOrganizationsResponse {
+    /// List of organizations.
+    pub organizations: Vec<models::Organization>,
+    /// Page offset.
+    pub start: Option<usize>,
+    /// Maximum number of items per page.
+    pub limit: Option<usize>,
+    /// Number of items per page.
+    pub length: Option<usize>,
+    /// Total number of elements.
+    pub total: Option<usize>,
+}
+
+impl ListOrganizations {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListOrganizationsResponse> {
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(ORGANIZATIONS_ENDPOINT, self).await
+        } else {
+            let organizations = self.api.http_get(ORGANIZATIONS_ENDPOINT, self).await?;
+            Ok(ListOrganizationsResponse {
+                start: None,
+                limit: None,
+                length: None,
+                total: None,
+                organizations,
+            })
+        }
+    }
+}
+
+impl Paginated for ListOrganizations {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListOrganizationsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListOrganizationsResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::Organization> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.organizations,
+                total: response.total,
+            })
+        })
+    }
+}
+
+/// Retrieve organization details.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct GetOrganization {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the organization to retrieve.
+    #[builder(private, setter(into))]
+    id: String,
+}
+
+/// Response for [`GetOrganization`].
+pub type GetOrganizationResponse = models::Organization;
+
+impl GetOrganizationBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<GetOrganizationResponse> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", ORGANIZATIONS_ENDPOINT, path_segment(&request.id));
+        request.api.http_get(&endpoint, &()).await
+    }
+}
+
+/// Retrieve organization details by name.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct GetOrganizationByName {
+    #[builder(private)]
+    api: ManagementApi,
+    /// Name of the organization to retrieve.
+    #[builder(private, setter(into))]
+    name: String,
+}
+
+/// Response for [`GetOrganizationByName`].
+pub type GetOrganizationByNameResponse = models::Organization;
+
+impl GetOrganizationByNameBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<GetOrganizationByNameResponse> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/name/{}",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&request.name)
+        );
+        request.api.http_get(&endpoint, &()).await
+    }
+}
+
+/// Create a new organization.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct CreateOrganization {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// Name of the organization, used in the authorization requests.
+    #[builder(private, setter(into))]
+    name: String,
+    /// Friendly name of the organization.
+    #[builder(setter(strip_option, into), default)]
+    display_name: Option<String>,
+    /// Branding of the login pages of the organization.
+    #[builder(setter(strip_option), default)]
+    branding: Option<models::OrganizationBranding>,
+    /// Metadata associated with the organization.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[builder(setter(custom), default)]
+    metadata: HashMap<String, String>,
+}
+
+/// Response for [`CreateOrganization`].
+pub type CreateOrganizationResponse = models::Organization;
+
+impl CreateOrganizationBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<CreateOrganizationResponse> {
+        let request = self.build()?;
+        request
+            .api
+            .http_post(ORGANIZATIONS_ENDPOINT, &request)
+            .await
+    }
+
+    /// Add one entry to the metadata of the organization.
+    pub fn metadata<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
+/// Update an organization.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct UpdateOrganization {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization to update.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Name of the organization, used in the authorization requests.
+    #[builder(setter(strip_option, into), default)]
+    name: Option<String>,
+    /// Friendly name of the organization.
+    #[builder(setter(strip_option, into), default)]
+    display_name: Option<String>,
+    /// Branding of the login pages of the organization.
+    #[builder(setter(strip_option), default)]
+    branding: Option<models::OrganizationBranding>,
+    /// Metadata associated with the organization, entries set to `None` are removed.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    #[builder(setter(custom), default)]
+    metadata: HashMap<String, Option<String>>,
+}
+
+/// Response for [`UpdateOrganization`].
+pub type UpdateOrganizationResponse = models::Organization;
+
+impl UpdateOrganizationBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<UpdateOrganizationResponse> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", ORGANIZATIONS_ENDPOINT, path_segment(&request.id));
+        request.api.http_patch(&endpoint, &request).await
+    }
+
+    /// Set one entry of the metadata of the organization.
+    pub fn metadata<K, V>(&mut self, key: K, value: V) -> &mut Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), Some(value.into()));
+        self
+    }
+
+    /// Remove one entry from the metadata of the organization.
+    pub fn remove_metadata<K: Into<String>>(&mut self, key: K) -> &mut Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), None);
+        self
+    }
+}
+
+/// Delete an organization.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct DeleteOrganization {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the organization to delete.
+    #[builder(private, setter(into))]
+    id: String,
+}
+
+impl DeleteOrganizationBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", ORGANIZATIONS_ENDPOINT, path_segment(&request.id));
+        request.api.http_delete(&endpoint).await
+    }
+}
+
+/// Retrieve the members of an organization, using checkpoint pagination.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListOrganizationMembers {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Checkpoint from which to start the results, as returned in the `next` field of the
+    /// previous page.
+    #[builder(setter(strip_option, into), default)]
+    from: Option<String>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(default = "DEFAULT_PER_PAGE")]
+    take: usize,
+    /// List of fields to include or exclude, `roles` must be requested to include the roles of
+    /// the members.
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
+    #[builder(setter(custom), default)]
+    fields: Vec<String>,
+    /// Whether specified fields are to be included.
+    #[builder(setter(strip_option), default)]
+    include_fields: Option<bool>,
+}
+
+/// Response for [`ListOrganizationMembers`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListOrganizationMembersResponse {
+    /// List of members.
+    pub members: Vec<models::OrganizationMember>,
+    /// Checkpoint from which to request the next page, if there are more results.
+    #[serde(default)]
+    pub next: Option<String>,
+}
+
+impl ListOrganizationMembers {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListOrganizationMembersResponse> {
+        let endpoint = format!(
+            "{}/{}/members",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&self.id)
+        );
+        self.api.http_get(&endpoint, self).await
+    }
+}
+
+impl ListOrganizationMembersBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListOrganizationMembersResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `from`.
+    pub fn into_stream(&self) -> CheckpointStream<models::OrganizationMember> {
+        let request = match self.build() {
+            Ok(request) => request,
+            Err(err) => return CheckpointStream::from_error(err),
+        };
+        CheckpointStream::new(request.from.clone(), move |from| {
+            let mut request = request.clone();
+            request.from = from;
+            async move {
+                let response = request.send().await?;
+                Ok(Checkpoint {
+                    items: response.members,
+                    next: response.next,
+                })
+            }
+            .boxed()
+        })
+    }
+
+    /// Append one element to the list of fields.
+    pub fn field<T: Into<String>>(&mut self, field: T) -> &mut Self {
+        self.fields.get_or_insert_with(Vec::new).push(field.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of fields.
+    pub fn fields<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.fields
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Add members to an organization.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct AddOrganizationMembers {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization to add the members to.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// IDs of the users to add.
+    #[builder(setter(custom), default)]
+    members: Vec<String>,
+}
+
+impl AddOrganizationMembersBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/members",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request.api.http_post_no_content(&endpoint, &request).await
+    }
+
+    /// Append one element to the list of members.
+    pub fn member<T: Into<String>>(&mut self, member: T) -> &mut Self {
+        self.members
+            .get_or_insert_with(Vec::new)
+            .push(member.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of members.
+    pub fn members<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.members
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Remove members from an organization.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct RemoveOrganizationMembers {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization to remove the members from.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// IDs of the users to remove.
+    #[builder(setter(custom), default)]
+    members: Vec<String>,
+}
+
+impl RemoveOrganizationMembersBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/members",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request.api.http_delete_with_body(&endpoint, &request).await
+    }
+
+    /// Append one element to the list of members.
+    pub fn member<T: Into<String>>(&mut self, member: T) -> &mut Self {
+        self.members
+            .get_or_insert_with(Vec::new)
+            .push(member.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of members.
+    pub fn members<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.members
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Retrieve the roles of a member of an organization.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListOrganizationMemberRoles {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// ID of the member.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    user_id: String,
+    /// Page index of the results to return. First page is 0.
+    #[builder(setter(strip_option), default)]
+    page: Option<usize>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(setter(strip_option), default)]
+    per_page: Option<usize>,
+    /// Return results inside an object that contains the total result count. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    include_totals: Option<bool>,
+}
+
+/// Response for [`ListOrganizationMemberRoles`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListOrganizationMemberRolesResponse {
+    /// List of roles.
+    pub roles: Vec<models::Role>,
+    /// Page offset.
+    pub start: Option<usize>,
+    /// Maximum number of items per page.
+    pub limit: Option<usize>,
+    /// Number of items per page.
+    pub length: Option<usize>,
+    /// Total number of elements.
+    pub total: Option<usize>,
+}
+
+impl ListOrganizationMemberRoles {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListOrganizationMemberRolesResponse> {
+        let endpoint = format!(
+            "{}/{}/members/{}/roles",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&self.id),
+            path_segment(&self.user_id)
+        );
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(&endpoint, self).await
+        } else {
+            let roles = self.api.http_get(&endpoint, self).await?;
+            Ok(ListOrganizationMemberRolesResponse {
+                start: None,
+                limit: None,
+                length: None,
+                total: None,
+                roles,
+            })
+        }
+    }
+}
+
+impl Paginated for ListOrganizationMemberRoles {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListOrganizationMemberRolesBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListOrganizationMemberRolesResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::Role> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.roles,
+                total: response.total,
+            })
+        })
+    }
+}
+
+/// Assign roles to a member of an organization.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct AssignOrganizationMemberRoles {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// ID of the member to assign the roles to.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    user_id: String,
+    /// IDs of the roles to assign.
+    #[builder(setter(custom), default)]
+    roles: Vec<String>,
+}
+
+impl AssignOrganizationMemberRolesBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/members/{}/roles",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&request.id),
+            path_segment(&request.user_id)
+        );
+        request.api.http_post_no_content(&endpoint, &request).await
+    }
+
+    /// Append one element to the list of roles.
+    pub fn role<T: Into<String>>(&mut self, role: T) -> &mut Self {
+        self.roles.get_or_insert_with(Vec::new).push(role.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of roles.
+    pub fn roles<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.roles
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Remove roles from a member of an organization.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct RemoveOrganizationMemberRoles {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// ID of the member to remove the roles from.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    user_id: String,
+    /// IDs of the roles to remove.
+    #[builder(setter(custom), default)]
+    roles: Vec<String>,
+}
+
+impl RemoveOrganizationMemberRolesBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/members/{}/roles",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&request.id),
+            path_segment(&request.user_id)
+        );
+        request.api.http_delete_with_body(&endpoint, &request).await
+    }
+
+    /// Append one element to the list of roles.
+    pub fn role<T: Into<String>>(&mut self, role: T) -> &mut Self {
+        self.roles.get_or_insert_with(Vec::new).push(role.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of roles.
+    pub fn roles<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.roles
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Retrieve the connections enabled for an organization.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListOrganizationConnections {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Page index of the results to return. First page is 0.
+    #[builder(setter(strip_option), default)]
+    page: Option<usize>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(setter(strip_option), default)]
+    per_page: Option<usize>,
+    /// Return results inside an object that contains the total result count. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    include_totals: Option<bool>,
+}
+
+/// Response for [`ListOrganizationConnections`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListOrganizationConnectionsResponse {
+    /// List of enabled connections.
+    pub enabled_connections: Vec<models::OrganizationConnection>,
+    /// Page offset.
+    pub start: Option<usize>,
+    /// Maximum number of items per page.
+    pub limit: Option<usize>,
+    /// Number of items per page.
+    pub length: Option<usize>,
+    /// Total number of elements.
+    pub total: Option<usize>,
+}
+
+impl ListOrganizationConnections {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListOrganizationConnectionsResponse> {
+        let endpoint = format!(
+            "{}/{}/enabled_connections",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&self.id)
+        );
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(&endpoint, self).await
+        } else {
+            let enabled_connections = self.api.http_get(&endpoint, self).await?;
+            Ok(ListOrganizationConnectionsResponse {
+                start: None,
+                limit: None,
+                length: None,
+                total: None,
+                enabled_connections,
+            })
+        }
+    }
+}
+
+impl Paginated for ListOrganizationConnections {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListOrganizationConnectionsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListOrganizationConnectionsResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::OrganizationConnection> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.enabled_connections,
+                total: response.total,
+            })
+        })
+    }
+}
+
+/// Enable a connection for an organization.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct AddOrganizationConnection {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// ID of the connection to enable.
+    #[builder(private, setter(into))]
+    connection_id: String,
+    /// Whether users logging in with the connection are automatically added as members of the
+    /// organization. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    assign_membership_on_login: Option<bool>,
+    /// Whether the connection is displayed as a button on the login page of the organization.
+    /// Default is `true`.
+    #[builder(setter(strip_option), default)]
+    show_as_button: Option<bool>,
+}
+
+/// Response for [`AddOrganizationConnection`].
+pub type AddOrganizationConnectionResponse = models::OrganizationConnection;
+
+impl AddOrganizationConnectionBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<AddOrganizationConnectionResponse> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/enabled_connections",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request.api.http_post(&endpoint, &request).await
+    }
+}
+
+/// Update a connection enabled for an organization.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct UpdateOrganizationConnection {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// ID of the connection to update.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    connection_id: String,
+    /// Whether users logging in with the connection are automatically added as members of the
+    /// organization.
+    #[builder(setter(strip_option), default)]
+    assign_membership_on_login: Option<bool>,
+    /// Whether the connection is displayed as a button on the login page of the organization.
+    #[builder(setter(strip_option), default)]
+    show_as_button: Option<bool>,
+}
+
+/// Response for [`UpdateOrganizationConnection`].
+pub type UpdateOrganizationConnectionResponse = models::OrganizationConnection;
+
+impl UpdateOrganizationConnectionBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<UpdateOrganizationConnectionResponse> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/enabled_connections/{}",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&request.id),
+            path_segment(&request.connection_id)
+        );
+        request.api.http_patch(&endpoint, &request).await
+    }
+}
+
+/// Disable a connection for an organization.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct RemoveOrganizationConnection {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    id: String,
+    /// ID of the connection to disable.
+    #[builder(private, setter(into))]
+    connection_id: String,
+}
+
+impl RemoveOrganizationConnectionBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/enabled_connections/{}",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&request.id),
+            path_segment(&request.connection_id)
+        );
+        request.api.http_delete(&endpoint).await
+    }
+}
+
+/// Retrieve the invitations of an organization.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListOrganizationInvitations {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Page index of the results to return. First page is 0.
+    #[builder(setter(strip_option), default)]
+    page: Option<usize>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(setter(strip_option), default)]
+    per_page: Option<usize>,
+    /// Return results inside an object that contains the total result count. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    include_totals: Option<bool>,
+}
+
+/// Response for [`ListOrganizationInvitations`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListOrganizationInvitationsResponse {
+    /// List of invitations.
+    pub invitations: Vec<models::OrganizationInvitation>,
+    /// Page offset.
+    pub start: Option<usize>,
+    /// Maximum number of items per page.
+    pub limit: Option<usize>,
+    /// Number of items per page.
+    pub length: Option<usize>,
+    /// Total number of elements.
+    pub total: Option<usize>,
+}
+
+impl ListOrganizationInvitations {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListOrganizationInvitationsResponse> {
+        let endpoint = format!(
+            "{}/{}/invitations",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&self.id)
+        );
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(&endpoint, self).await
+        } else {
+            let invitations = self.api.http_get(&endpoint, self).await?;
+            Ok(ListOrganizationInvitationsResponse {
+                start: None,
+                limit: None,
+                length: None,
+                total: None,
+                invitations,
+            })
+        }
+    }
+}
+
+impl Paginated for ListOrganizationInvitations {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListOrganizationInvitationsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListOrganizationInvitationsResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::OrganizationInvitation> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.invitations,
+                total: response.total,
+            })
+        })
+    }
+}
+
+/// Invite a user to join an organization.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct CreateOrganizationInvitation {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Person sending the invitation.
+    #[builder(private)]
+    inviter: models::OrganizationInviter,
+    /// Person receiving the invitation.
+    #[builder(private)]
+    invitee: models::OrganizationInvitee,
+    /// ID of the client the invitee is redirected to once the invitation is accepted.
+    #[builder(private, setter(into))]
+    client_id: String,
+    /// ID of the connection the invitee must use to authenticate.
+    #[builder(setter(strip_option, into), default)]
+    connection_id: Option<String>,
+    /// Data of the user to which the invitee has read-only access.
+    #[builder(setter(strip_option), default)]
+    app_metadata: Option<JsonValue>,
+    /// Data of the user to which the invitee has read/write access.
+    #[builder(setter(strip_option), default)]
+    user_metadata: Option<JsonValue>,
+    /// Number of seconds before the invitation expires. Default and maximum is 604800 seconds
+    /// (7 days).
+    #[builder(setter(strip_option), default)]
+    ttl_sec: Option<u64>,
+    /// IDs of the roles assigned to the invitee once the invitation is accepted.
+    #[builder(setter(custom), default)]
+    roles: Vec<String>,
+    /// Whether the invitation email is sent to the invitee. Default is `true`.
+    #[builder(setter(strip_option), default)]
+    send_invitation_email: Option<bool>,
+}
+
+/// Response for [`CreateOrganizationInvitation`].
+pub type CreateOrganizationInvitationResponse = models::OrganizationInvitation;
+
+impl CreateOrganizationInvitationBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<CreateOrganizationInvitationResponse> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/invitations",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request.api.http_post(&endpoint, &request).await
+    }
+
+    /// Append one element to the list of roles.
+    pub fn role<T: Into<String>>(&mut self, role: T) -> &mut Self {
+        self.roles.get_or_insert_with(Vec::new).push(role.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of roles.
+    pub fn roles<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.roles
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Delete an invitation to join an organization.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct DeleteOrganizationInvitation {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the organization.
+    #[builder(private, setter(into))]
+    id: String,
+    /// ID of the invitation to delete.
+    #[builder(private, setter(into))]
+    invitation_id: String,
+}
+
+impl DeleteOrganizationInvitationBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/invitations/{}",
+            ORGANIZATIONS_ENDPOINT,
+            path_segment(&request.id),
+            path_segment(&request.invitation_id)
+        );
+        request.api.http_delete(&endpoint).await
+    }
+}
@@ -0,0 +1,300 @@
+//! Lazy pagination of the list endpoints of the Management API.
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{future::BoxFuture, Future, FutureExt, Stream};
+
+use crate::{Error, Result};
+
+/// Default number of results per page requested by the streams.
+pub(crate) const DEFAULT_PER_PAGE: usize = 50;
+
+/// A page of results.
+pub(crate) struct Page<T> {
+    /// Items of the page.
+    pub(crate) items: Vec<T>,
+    /// Total number of items, if reported by the endpoint.
+    pub(crate) total: Option<usize>,
+}
+
+/// Pagination parameters of a list request, borrowed from the request.
+pub(crate) struct Pagination<'a> {
+    /// Page index of the results to return.
+    pub(crate) page: &'a mut Option<usize>,
+    /// Number of results per page.
+    pub(crate) per_page: &'a mut Option<usize>,
+    /// Whether to return the total result count.
+    pub(crate) include_totals: &'a mut Option<bool>,
+}
+
+/// A list request of an endpoint using offset pagination.
+pub(crate) trait Paginated: Clone + Send + 'static {
+    /// Returns the pagination parameters of the request.
+    fn pagination(&mut self) -> Pagination<'_>;
+}
+
+/// A page of results of an endpoint using checkpoint pagination.
+pub(crate) struct Checkpoint<T> {
+    /// Items of the page.
+    pub(crate) items: Vec<T>,
+    /// Checkpoint from which to request the next page, if there are more results.
+    pub(crate) next: Option<String>,
+}
+
+/// Function fetching the page with the given index.
+type FetchPage<T> = Box<dyn FnMut(usize) -> BoxFuture<'static, Result<Page<T>>> + Send>;
+
+/// Function fetching the page starting from the given checkpoint.
+type FetchCheckpoint<T> =
+    Box<dyn FnMut(Option<String>) -> BoxFuture<'static, Result<Checkpoint<T>>> + Send>;
+
+/// A [`Stream`] walking lazily through the pages of a list endpoint.
+///
+/// Pages are requested one at a time, as the items of the previous one are consumed. The stream
+/// ends after the first error.
+pub struct PageStream<T> {
+    /// Function fetching a page.
+    fetch: FetchPage<T>,
+    /// The request of the current page, if any.
+    pending: Option<BoxFuture<'static, Result<Page<T>>>>,
+    /// Items of the current page not yet returned.
+    items: VecDeque<T>,
+    /// Index of the next page.
+    page: usize,
+    /// Number of results per page.
+    per_page: usize,
+    /// Maximum number of results the endpoint can return, if any.
+    limit: Option<usize>,
+    /// Total number of items, if reported by the endpoint.
+    total: Option<usize>,
+    /// An error to return before ending the stream.
+    error: Option<Error>,
+    /// Whether all the pages have been fetched.
+    done: bool,
+}
+
+impl<T> PageStream<T> {
+    /// Create a new stream starting from the given page.
+    pub(crate) fn new<F>(page: usize, per_page: usize, fetch: F) -> Self
+    where
+        F: FnMut(usize) -> BoxFuture<'static, Result<Page<T>>> + Send + 'static,
+    {
+        Self {
+            fetch: Box::new(fetch),
+            pending: None,
+            items: VecDeque::new(),
+            page,
+            per_page: per_page.max(1),
+            limit: None,
+            total: None,
+            error: None,
+            done: false,
+        }
+    }
+
+    /// Create a stream walking through the pages of a list request, starting from its `page`.
+    ///
+    /// The pages are requested with the total result count and `per_page` defaulting to
+    /// [`DEFAULT_PER_PAGE`], `fetch` sends the request updated for each page.
+    pub(crate) fn from_request<R, F, Fut>(request: Result<R>, fetch: F) -> Self
+    where
+        T: 'static,
+        R: Paginated,
+        F: Fn(R) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Page<T>>> + Send + 'static,
+    {
+        let mut request = match request {
+            Ok(request) => request,
+            Err(err) => return Self::from_error(err),
+        };
+        let pagination = request.pagination();
+        let first_page = pagination.page.unwrap_or(0);
+        let per_page = *pagination.per_page.get_or_insert(DEFAULT_PER_PAGE);
+        *pagination.include_totals = Some(true);
+        Self::new(first_page, per_page, move |page| {
+            let mut request = request.clone();
+            *request.pagination().page = Some(page);
+            fetch(request).boxed()
+        })
+    }
+
+    /// Create a stream yielding only the given error.
+    pub(crate) fn from_error(error: Error) -> Self {
+        let mut stream = Self::new(0, DEFAULT_PER_PAGE, |_| unreachable!());
+        stream.error = Some(error);
+        stream
+    }
+
+    /// Set the maximum number of results the endpoint can return, the stream stops before the first
+    /// page that would go past it.
+    pub(crate) fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the total number of items, available once the first page has been fetched if the
+    /// endpoint reports it.
+    pub fn total(&self) -> Option<usize> {
+        self.total
+    }
+
+    /// Update the state of the stream with a fetched page.
+    fn push_page(&mut self, page: Page<T>) {
+        let len = page.items.len();
+        self.page += 1;
+        self.total = page.total.or(self.total);
+        self.items.extend(page.items);
+        // Number of results up to the end of the fetched page.
+        let offset = self.page * self.per_page;
+        // The endpoint rejects any page ending past its limit, even partially.
+        self.done = len < self.per_page
+            || self.total.is_some_and(|total| offset >= total)
+            || self
+                .limit
+                .is_some_and(|limit| offset + self.per_page > limit);
+    }
+}
+
+// The stream never pins its fields, the pending request is already boxed.
+impl<T> Unpin for PageStream<T> {}
+
+impl<T> Stream for PageStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.items.pop_front() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+            if let Some(error) = this.error.take() {
+                this.done = true;
+                return Poll::Ready(Some(Err(error)));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+            let page = this.page;
+            let pending = this.pending.get_or_insert_with(|| (this.fetch)(page));
+            match pending.as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(result) => {
+                    this.pending = None;
+                    match result {
+                        Ok(page) => this.push_page(page),
+                        Err(error) => this.error = Some(error),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A [`Stream`] walking lazily through the pages of a list endpoint using checkpoint pagination,
+/// where each page gives the checkpoint from which to request the next one.
+///
+/// Pages are requested one at a time, as the items of the previous one are consumed. The stream
+/// ends after the first error.
+pub struct CheckpointStream<T> {
+    /// Function fetching a page.
+    fetch: FetchCheckpoint<T>,
+    /// The request of the current page, if any.
+    pending: Option<BoxFuture<'static, Result<Checkpoint<T>>>>,
+    /// Items of the current page not yet returned.
+    items: VecDeque<T>,
+    /// Checkpoint of the next page.
+    from: Option<String>,
+    /// An error to return before ending the stream.
+    error: Option<Error>,
+    /// Whether all the pages have been fetched.
+    done: bool,
+}
+
+impl<T> CheckpointStream<T> {
+    /// Create a new stream starting from the given checkpoint, or from the first result.
+    pub(crate) fn new<F>(from: Option<String>, fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> BoxFuture<'static, Result<Checkpoint<T>>> + Send + 'static,
+    {
+        Self {
+            fetch: Box::new(fetch),
+            pending: None,
+            items: VecDeque::new(),
+            from,
+            error: None,
+            done: false,
+        }
+    }
+
+    /// Create a stream yielding only the given error.
+    pub(crate) fn from_error(error: Error) -> Self {
+        let mut stream = Self::new(None, |_| unreachable!());
+        stream.error = Some(error);
+        stream
+    }
+
+    /// Update the state of the stream with a fetched page.
+    fn push_page(&mut self, page: Checkpoint<T>) {
+        self.done = page.items.is_empty() || page.next.is_none();
+        self.from = page.next;
+        self.items.extend(page.items);
+    }
+}
+
+impl<T> Unpin for CheckpointStream<T> {}
+
+impl<T> Stream for CheckpointStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.items.pop_front() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+            if let Some(error) = this.error.take() {
+                this.done = true;
+                return Poll::Ready(Some(Err(error)));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+            let from = this.from.clone();
+            let pending = this.pending.get_or_insert_with(|| (this.fetch)(from));
+            match pending.as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(result) => {
+                    this.pending = None;
+                    match result {
+                        Ok(page) => this.push_page(page),
+                        Err(error) => this.error = Some(error),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for CheckpointStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CheckpointStream")
+            .field("from", &self.from)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<T> std::fmt::Debug for PageStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PageStream")
+            .field("page", &self.page)
+            .field("per_page", &self.per_page)
+            .field("total", &self.total)
+            .field("done", &self.done)
+            .finish()
+    }
+}
@@ -0,0 +1,130 @@
+//! Retry policy and rate limit state of the API clients.
+
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    Method, StatusCode,
+};
+use time::OffsetDateTime;
+
+const HEADER_RATELIMIT_LIMIT: &str = "x-ratelimit-limit";
+const HEADER_RATELIMIT_REMAINING: &str = "x-ratelimit-remaining";
+const HEADER_RATELIMIT_RESET: &str = "x-ratelimit-reset";
+
+/// Policy used to retry failed requests.
+///
+/// Only requests with an idempotent method are retried, when rejected with `429 Too Many Requests`
+/// or failed with a `5xx` status code. The delay between two attempts honours the `Retry-After`
+/// header when present, as well as the `x-ratelimit-reset` header of rate limited requests,
+/// otherwise it grows exponentially with full jitter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one. A value of `1` disables retries.
+    pub max_attempts: u32,
+    /// Base delay of the exponential backoff.
+    pub base_delay: Duration,
+    /// Maximum delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Whether a request that failed with the given status should be attempted again.
+    pub(crate) fn should_retry(&self, attempt: u32, method: &Method, status: StatusCode) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        let idempotent = matches!(
+            *method,
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+        );
+        idempotent && (status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
+    }
+
+    /// Delay to wait before the next attempt.
+    pub(crate) fn delay(&self, attempt: u32, status: StatusCode, headers: &HeaderMap) -> Duration {
+        let delay = retry_after(status, headers).unwrap_or_else(|| {
+            let backoff = self
+                .base_delay
+                .saturating_mul(2_u32.saturating_pow(attempt.saturating_sub(1)))
+                .min(self.max_delay);
+            rand::thread_rng().gen_range(Duration::ZERO..=backoff)
+        });
+        delay.min(self.max_delay)
+    }
+}
+
+/// Returns the delay requested by the server, if any.
+fn retry_after(status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+    if let Some(secs) = header_value(headers, RETRY_AFTER.as_str()) {
+        return Some(Duration::from_secs(secs.max(0) as u64));
+    }
+    // Auth0 sends the rate limit headers with every response, the reset only matters once the
+    // limit has been reached.
+    if status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    header_value(headers, HEADER_RATELIMIT_RESET).map(|reset| {
+        let secs = reset - OffsetDateTime::now_utc().unix_timestamp();
+        Duration::from_secs(secs.max(0) as u64)
+    })
+}
+
+/// Parse the value of an integer header.
+fn header_value(headers: &HeaderMap, name: &str) -> Option<i64> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+/// Rate limit state as reported by Auth0 in the response headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Maximum number of requests available in the current time frame.
+    pub limit: Option<u64>,
+    /// Number of remaining requests in the current time frame.
+    pub remaining: Option<u64>,
+    /// Date and time at which the time frame is reset.
+    pub reset: Option<OffsetDateTime>,
+}
+
+impl RateLimit {
+    /// Parse the rate limit headers, returns `None` if none of them is present.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let parse = |name| header_value(headers, name);
+        let rate_limit = Self {
+            limit: parse(HEADER_RATELIMIT_LIMIT).map(|value| value.max(0) as u64),
+            remaining: parse(HEADER_RATELIMIT_REMAINING).map(|value| value.max(0) as u64),
+            reset: parse(HEADER_RATELIMIT_RESET)
+                .and_then(|value| OffsetDateTime::from_unix_timestamp(value).ok()),
+        };
+        if rate_limit.limit.is_none()
+            && rate_limit.remaining.is_none()
+            && rate_limit.reset.is_none()
+        {
+            None
+        } else {
+            Some(rate_limit)
+        }
+    }
+}
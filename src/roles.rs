@@ -0,0 +1,635 @@
+//! Implementation of the Roles methods of the Management API.
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    http::path_segment,
+    models,
+    pagination::{Page, PageStream, Paginated, Pagination},
+    ManagementApi, Result,
+};
+
+const ROLES_ENDPOINT: &str = "/api/v2/roles";
+
+/// This struct provides an implementation of the Roles methods of the Management API.
+pub struct Roles {
+    pub(crate) api: ManagementApi,
+}
+
+impl Roles {
+    /// Retrieve the list of roles, implementation of [`/api/v2/roles`] endpoint.
+    ///
+    /// [`/api/v2/roles`]: https://auth0.com/docs/api/management/v2/roles/get-roles
+    pub fn list(&self) -> ListRolesBuilder {
+        let mut builder = ListRolesBuilder::default();
+        builder.api(self.api.clone());
+        builder
+    }
+
+    /// Retrieve role details, implementation of [`/api/v2/roles/{id}`] endpoint.
+    ///
+    /// [`/api/v2/roles/{id}`]: https://auth0.com/docs/api/management/v2/roles/get-roles-by-id
+    pub fn get<T: Into<String>>(&self, id: T) -> GetRoleBuilder {
+        let mut builder = GetRoleBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Create a new role, implementation of [`/api/v2/roles`] endpoint.
+    ///
+    /// [`/api/v2/roles`]: https://auth0.com/docs/api/management/v2/roles/post-roles
+    pub fn create<T: Into<String>>(&self, name: T) -> CreateRoleBuilder {
+        let mut builder = CreateRoleBuilder::default();
+        builder.api(self.api.clone()).name(name);
+        builder
+    }
+
+    /// Update a role, implementation of [`/api/v2/roles/{id}`] endpoint.
+    ///
+    /// [`/api/v2/roles/{id}`]: https://auth0.com/docs/api/management/v2/roles/patch-roles-by-id
+    pub fn update<T: Into<String>>(&self, id: T) -> UpdateRoleBuilder {
+        let mut builder = UpdateRoleBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Delete a role, implementation of [`/api/v2/roles/{id}`] endpoint.
+    ///
+    /// [`/api/v2/roles/{id}`]: https://auth0.com/docs/api/management/v2/roles/delete-roles-by-id
+    pub fn delete<T: Into<String>>(&self, id: T) -> DeleteRoleBuilder {
+        let mut builder = DeleteRoleBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Retrieve the permissions granted by a role, implementation of
+    /// [`/api/v2/roles/{id}/permissions`] endpoint.
+    ///
+    /// [`/api/v2/roles/{id}/permissions`]: https://auth0.com/docs/api/management/v2/roles/get-role-permission
+    pub fn permissions<T: Into<String>>(&self, id: T) -> ListRolePermissionsBuilder {
+        let mut builder = ListRolePermissionsBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Add permissions to a role, implementation of [`/api/v2/roles/{id}/permissions`] endpoint.
+    ///
+    /// [`/api/v2/roles/{id}/permissions`]: https://auth0.com/docs/api/management/v2/roles/post-role-permission-assignment
+    pub fn add_permissions<T: Into<String>>(&self, id: T) -> AddRolePermissionsBuilder {
+        let mut builder = AddRolePermissionsBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Remove permissions from a role, implementation of [`/api/v2/roles/{id}/permissions`]
+    /// endpoint.
+    ///
+    /// [`/api/v2/roles/{id}/permissions`]: https://auth0.com/docs/api/management/v2/roles/delete-role-permission-assignment
+    pub fn remove_permissions<T: Into<String>>(&self, id: T) -> RemoveRolePermissionsBuilder {
+        let mut builder = RemoveRolePermissionsBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Retrieve the users assigned to a role, implementation of [`/api/v2/roles/{id}/users`]
+    /// endpoint.
+    ///
+    /// [`/api/v2/roles/{id}/users`]: https://auth0.com/docs/api/management/v2/roles/get-role-user
+    pub fn users<T: Into<String>>(&self, id: T) -> ListRoleUsersBuilder {
+        let mut builder = ListRoleUsersBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Assign users to a role, implementation of [`/api/v2/roles/{id}/users`] endpoint.
+    ///
+    /// [`/api/v2/roles/{id}/users`]: https://auth0.com/docs/api/management/v2/roles/post-role-users
+    pub fn assign_users<T: Into<String>>(&self, id: T) -> AssignRoleUsersBuilder {
+        let mut builder = AssignRoleUsersBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+}
+
+/// Retrieve roles matching provided filters.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListRoles {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// Page index of the results to return. First page is 0.
+    #[builder(setter(strip_option), default)]
+    page: Option<usize>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(setter(strip_option), default)]
+    per_page: Option<usize>,
+    /// Return results inside an object that contains the total result count. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    include_totals: Option<bool>,
+    /// Optional filter on name (case-insensitive).
+    #[builder(setter(strip_option, into), default)]
+    name_filter: Option<String>,
+}
+
+/// Response for [`ListRoles`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListRolesResponse {
+    /// List of roles.
+    pub roles: Vec<models::Role>,
+    /// Page offset.
+    pub start: Option<usize>,
+    /// Maximum number of items per page.
+    pub limit: Option<usize>,
+    /// Number of items per page.
+    pub length: Option<usize>,
+    /// Total number of elements.
+    pub total: Option<usize>,
+}
+
+impl ListRoles {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListRolesResponse> {
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(ROLES_ENDPOINT, self).await
+        } else {
+            let roles = self.api.http_get(ROLES_ENDPOINT, self).await?;
+            Ok(ListRolesResponse {
+                start: None,
+                limit: None,
+                length: None,
+                total: None,
+                roles,
+            })
+        }
+    }
+}
+
+impl Paginated for ListRoles {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListRolesBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListRolesResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::Role> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.roles,
+                total: response.total,
+            })
+        })
+    }
+}
+
+/// Retrieve role details.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct GetRole {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the role to retrieve.
+    #[builder(private, setter(into))]
+    id: String,
+}
+
+/// Response for [`GetRole`].
+pub type GetRoleResponse = models::Role;
+
+impl GetRoleBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<GetRoleResponse> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", ROLES_ENDPOINT, path_segment(&request.id));
+        request.api.http_get(&endpoint, &()).await
+    }
+}
+
+/// Create a new role.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct CreateRole {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// Name of the role.
+    #[builder(private, setter(into))]
+    name: String,
+    /// Description of the role.
+    #[builder(setter(strip_option, into), default)]
+    description: Option<String>,
+}
+
+/// Response for [`CreateRole`].
+pub type CreateRoleResponse = models::Role;
+
+impl CreateRoleBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<CreateRoleResponse> {
+        let request = self.build()?;
+        request.api.http_post(ROLES_ENDPOINT, &request).await
+    }
+}
+
+/// Update a role.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct UpdateRole {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the role to update.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Name of the role.
+    #[builder(setter(strip_option, into), default)]
+    name: Option<String>,
+    /// Description of the role.
+    #[builder(setter(strip_option, into), default)]
+    description: Option<String>,
+}
+
+/// Response for [`UpdateRole`].
+pub type UpdateRoleResponse = models::Role;
+
+impl UpdateRoleBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<UpdateRoleResponse> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", ROLES_ENDPOINT, path_segment(&request.id));
+        request.api.http_patch(&endpoint, &request).await
+    }
+}
+
+/// Delete a role.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct DeleteRole {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the role to delete.
+    #[builder(private, setter(into))]
+    id: String,
+}
+
+impl DeleteRoleBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", ROLES_ENDPOINT, path_segment(&request.id));
+        request.api.http_delete(&endpoint).await
+    }
+}
+
+/// Retrieve the permissions granted by a role.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListRolePermissions {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the role to list the permissions of.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Page index of the results to return. First page is 0.
+    #[builder(setter(strip_option), default)]
+    page: Option<usize>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(setter(strip_option), default)]
+    per_page: Option<usize>,
+    /// Return results inside an object that contains the total result count. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    include_totals: Option<bool>,
+}
+
+/// Response for [`ListRolePermissions`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListRolePermissionsResponse {
+    /// List of permissions.
+    pub permissions: Vec<models::Permission>,
+    /// Page offset.
+    pub start: Option<usize>,
+    /// Maximum number of items per page.
+    pub limit: Option<usize>,
+    /// Number of items per page.
+    pub length: Option<usize>,
+    /// Total number of elements.
+    pub total: Option<usize>,
+}
+
+impl ListRolePermissions {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListRolePermissionsResponse> {
+        let endpoint = format!("{}/{}/permissions", ROLES_ENDPOINT, path_segment(&self.id));
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(&endpoint, self).await
+        } else {
+            let permissions = self.api.http_get(&endpoint, self).await?;
+            Ok(ListRolePermissionsResponse {
+                start: None,
+                limit: None,
+                length: None,
+                total: None,
+                permissions,
+            })
+        }
+    }
+}
+
+impl Paginated for ListRolePermissions {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListRolePermissionsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListRolePermissionsResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::Permission> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.permissions,
+                total: response.total,
+            })
+        })
+    }
+}
+
+/// Add permissions to a role.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct AddRolePermissions {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the role to add the permissions to.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Permissions to add.
+    #[builder(setter(custom), default)]
+    permissions: Vec<models::Permission>,
+}
+
+impl AddRolePermissionsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/permissions",
+            ROLES_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request.api.http_post_no_content(&endpoint, &request).await
+    }
+
+    /// Append a permission of an API (resource server) to the list of permissions.
+    pub fn permission<T, U>(
+        &mut self,
+        resource_server_identifier: T,
+        permission_name: U,
+    ) -> &mut Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.permissions
+            .get_or_insert_with(Vec::new)
+            .push(models::Permission::new(
+                resource_server_identifier,
+                permission_name,
+            ));
+        self
+    }
+
+    /// Append the contents of iterator to the list of permissions.
+    pub fn permissions<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = models::Permission>,
+    {
+        self.permissions.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+}
+
+/// Remove permissions from a role.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct RemoveRolePermissions {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the role to remove the permissions from.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Permissions to remove.
+    #[builder(setter(custom), default)]
+    permissions: Vec<models::Permission>,
+}
+
+impl RemoveRolePermissionsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/permissions",
+            ROLES_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request.api.http_delete_with_body(&endpoint, &request).await
+    }
+
+    /// Append a permission of an API (resource server) to the list of permissions.
+    pub fn permission<T, U>(
+        &mut self,
+        resource_server_identifier: T,
+        permission_name: U,
+    ) -> &mut Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.permissions
+            .get_or_insert_with(Vec::new)
+            .push(models::Permission::new(
+                resource_server_identifier,
+                permission_name,
+            ));
+        self
+    }
+
+    /// Append the contents of iterator to the list of permissions.
+    pub fn permissions<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = models::Permission>,
+    {
+        self.permissions.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+}
+
+/// Retrieve the users assigned to a role.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListRoleUsers {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the role to list the users of.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Page index of the results to return. First page is 0.
+    #[builder(setter(strip_option), default)]
+    page: Option<usize>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(setter(strip_option), default)]
+    per_page: Option<usize>,
+    /// Return results inside an object that contains the total result count. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    include_totals: Option<bool>,
+}
+
+/// Response for [`ListRoleUsers`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListRoleUsersResponse {
+    /// List of users, with only their ID, name, email and picture.
+    pub users: Vec<models::User>,
+    /// Page offset.
+    pub start: Option<usize>,
+    /// Maximum number of items per page.
+    pub limit: Option<usize>,
+    /// Number of items per page.
+    pub length: Option<usize>,
+    /// Total number of elements.
+    pub total: Option<usize>,
+}
+
+impl ListRoleUsers {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListRoleUsersResponse> {
+        let endpoint = format!("{}/{}/users", ROLES_ENDPOINT, path_segment(&self.id));
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(&endpoint, self).await
+        } else {
+            let users = self.api.http_get(&endpoint, self).await?;
+            Ok(ListRoleUsersResponse {
+                start: None,
+                limit: None,
+                length: None,
+                total: None,
+                users,
+            })
+        }
+    }
+}
+
+impl Paginated for ListRoleUsers {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListRoleUsersBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListRoleUsersResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::User> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.users,
+                total: response.total,
+            })
+        })
+    }
+}
+
+/// Assign users to a role.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct AssignRoleUsers {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the role to assign the users to.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// IDs of the users to assign.
+    #[builder(setter(custom), default)]
+    users: Vec<String>,
+}
+
+impl AssignRoleUsersBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}/users", ROLES_ENDPOINT, path_segment(&request.id));
+        request.api.http_post_no_content(&endpoint, &request).await
+    }
+
+    /// Append one element to the list of users.
+    pub fn user<T: Into<String>>(&mut self, user: T) -> &mut Self {
+        self.users.get_or_insert_with(Vec::new).push(user.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of users.
+    pub fn users<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.users
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
@@ -1,13 +1,20 @@
 //! Implementation of the Users methods of the Management API.
 
-use anyhow::Result;
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use serde_with::{formats::CommaSeparator, serde_as, StringWithSeparator};
 
-use crate::{models, ManagementApi};
+use crate::{
+    http::path_segment,
+    models,
+    pagination::{Page, PageStream, Paginated, Pagination},
+    ManagementApi, Result,
+};
 
 const USERS_ENDPOINT: &str = "/api/v2/users";
+/// Maximum number of users returned by the search endpoint.
+const USERS_SEARCH_LIMIT: usize = 1000;
 
 /// Search engine version.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
@@ -41,6 +48,92 @@ impl Users {
         builder.api(self.api.clone()).id(id);
         builder
     }
+
+    /// Create a new user for a given database or passwordless connection, implementation of
+    /// [`/api/v2/users`] endpoint.
+    ///
+    /// [`/api/v2/users`]: https://auth0.com/docs/api/management/v2/users/post-users
+    pub fn create<T: Into<String>>(&self, connection: T) -> CreateUserBuilder {
+        let mut builder = CreateUserBuilder::default();
+        builder.api(self.api.clone()).connection(connection);
+        builder
+    }
+
+    /// Update a user, implementation of [`/api/v2/users/{id}`] endpoint.
+    ///
+    /// [`/api/v2/users/{id}`]: https://auth0.com/docs/api/management/v2/users/patch-users-by-id
+    pub fn update<T: Into<String>>(&self, id: T) -> UpdateUserBuilder {
+        let mut builder = UpdateUserBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Delete a user, implementation of [`/api/v2/users/{id}`] endpoint.
+    ///
+    /// [`/api/v2/users/{id}`]: https://auth0.com/docs/api/management/v2/users/delete-users-by-id
+    pub fn delete<T: Into<String>>(&self, id: T) -> DeleteUserBuilder {
+        let mut builder = DeleteUserBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Retrieve the roles assigned to a user, implementation of [`/api/v2/users/{id}/roles`]
+    /// endpoint.
+    ///
+    /// [`/api/v2/users/{id}/roles`]: https://auth0.com/docs/api/management/v2/users/get-user-roles
+    pub fn roles<T: Into<String>>(&self, id: T) -> ListUserRolesBuilder {
+        let mut builder = ListUserRolesBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Assign roles to a user, implementation of [`/api/v2/users/{id}/roles`] endpoint.
+    ///
+    /// [`/api/v2/users/{id}/roles`]: https://auth0.com/docs/api/management/v2/users/post-user-roles
+    pub fn assign_roles<T: Into<String>>(&self, id: T) -> AssignUserRolesBuilder {
+        let mut builder = AssignUserRolesBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Remove roles from a user, implementation of [`/api/v2/users/{id}/roles`] endpoint.
+    ///
+    /// [`/api/v2/users/{id}/roles`]: https://auth0.com/docs/api/management/v2/users/delete-user-roles
+    pub fn remove_roles<T: Into<String>>(&self, id: T) -> RemoveUserRolesBuilder {
+        let mut builder = RemoveUserRolesBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Retrieve the permissions granted to a user, directly or through roles, implementation of
+    /// [`/api/v2/users/{id}/permissions`] endpoint.
+    ///
+    /// [`/api/v2/users/{id}/permissions`]: https://auth0.com/docs/api/management/v2/users/get-permissions
+    pub fn permissions<T: Into<String>>(&self, id: T) -> ListUserPermissionsBuilder {
+        let mut builder = ListUserPermissionsBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Assign permissions to a user, implementation of [`/api/v2/users/{id}/permissions`]
+    /// endpoint.
+    ///
+    /// [`/api/v2/users/{id}/permissions`]: https://auth0.com/docs/api/management/v2/users/post-permissions
+    pub fn assign_permissions<T: Into<String>>(&self, id: T) -> AssignUserPermissionsBuilder {
+        let mut builder = AssignUserPermissionsBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
+
+    /// Remove permissions from a user, implementation of [`/api/v2/users/{id}/permissions`]
+    /// endpoint.
+    ///
+    /// [`/api/v2/users/{id}/permissions`]: https://auth0.com/docs/api/management/v2/users/delete-permissions
+    pub fn remove_permissions<T: Into<String>>(&self, id: T) -> RemoveUserPermissionsBuilder {
+        let mut builder = RemoveUserPermissionsBuilder::default();
+        builder.api(self.api.clone()).id(id);
+        builder
+    }
 }
 
 /// Retrieve details of users.
@@ -49,8 +142,8 @@ impl Users {
     Option => #[serde(skip_serializing_if = "Option::is_none")],
     Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
 )]
-#[derive(Builder, Debug, Serialize)]
-#[builder(build_fn(private, error = "anyhow::Error"))]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
 pub struct ListUsers {
     #[builder(private)]
     #[serde(skip)]
@@ -100,14 +193,13 @@ pub struct ListUsersResponse {
     pub total: Option<usize>,
 }
 
-impl ListUsersBuilder {
+impl ListUsers {
     /// Send the API request.
-    pub async fn send(&self) -> Result<ListUsersResponse> {
-        let request = self.build()?;
-        if request.include_totals.unwrap_or(false) {
-            request.api.http_get(USERS_ENDPOINT, &request).await
+    async fn send(&self) -> Result<ListUsersResponse> {
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(USERS_ENDPOINT, self).await
         } else {
-            let users = request.api.http_get(USERS_ENDPOINT, &request).await?;
+            let users = self.api.http_get(USERS_ENDPOINT, self).await?;
             Ok(ListUsersResponse {
                 start: None,
                 limit: None,
@@ -117,6 +209,39 @@ impl ListUsersBuilder {
             })
         }
     }
+}
+
+impl Paginated for ListUsers {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListUsersBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListUsersResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    ///
+    /// The search endpoint returns at most 1000 users, use [export jobs] to retrieve more.
+    ///
+    /// [export jobs]: https://auth0.com/docs/manage-users/user-migration/bulk-user-exports
+    pub fn into_stream(&self) -> PageStream<models::User> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.users,
+                total: response.total,
+            })
+        })
+        .with_limit(USERS_SEARCH_LIMIT)
+    }
 
     /// Append one element to the list of fields.
     pub fn field<T: Into<String>>(&mut self, field: T) -> &mut Self {
@@ -144,7 +269,7 @@ impl ListUsersBuilder {
     Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
 )]
 #[derive(Builder, Debug, Serialize)]
-#[builder(build_fn(private, error = "anyhow::Error"))]
+#[builder(build_fn(private, error = "crate::Error"))]
 pub struct GetUser {
     #[builder(private)]
     #[serde(skip)]
@@ -169,7 +294,7 @@ impl GetUserBuilder {
     /// Send the API request.
     pub async fn send(&self) -> Result<GetUserResponse> {
         let request = self.build()?;
-        let endpoint = format!("{}/{}", USERS_ENDPOINT, request.id);
+        let endpoint = format!("{}/{}", USERS_ENDPOINT, path_segment(&request.id));
         request.api.http_get(&endpoint, &request).await
     }
 
@@ -191,3 +316,561 @@ impl GetUserBuilder {
         self
     }
 }
+
+/// Create a new user.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct CreateUser {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// Name of the connection this user should be created in.
+    #[builder(private, setter(into))]
+    connection: String,
+    /// The user's email.
+    #[builder(setter(strip_option, into), default)]
+    email: Option<String>,
+    /// The user's phone number (following the E.164 recommendation), only valid for users from
+    /// SMS connections.
+    #[builder(setter(strip_option, into), default)]
+    phone_number: Option<String>,
+    /// Data related to the user that does not affect the application's core functionality.
+    #[builder(setter(strip_option), default)]
+    user_metadata: Option<JsonValue>,
+    /// Whether this user was blocked by an administrator.
+    #[builder(setter(strip_option), default)]
+    blocked: Option<bool>,
+    /// Whether this email address is verified.
+    #[builder(setter(strip_option), default)]
+    email_verified: Option<bool>,
+    /// Whether this phone number has been verified.
+    #[builder(setter(strip_option), default)]
+    phone_verified: Option<bool>,
+    /// Data related to the user that does affect the application's core functionality.
+    #[builder(setter(strip_option), default)]
+    app_metadata: Option<JsonValue>,
+    /// The user's given name(s).
+    #[builder(setter(strip_option, into), default)]
+    given_name: Option<String>,
+    /// The user's family name(s).
+    #[builder(setter(strip_option, into), default)]
+    family_name: Option<String>,
+    /// The user's full name.
+    #[builder(setter(strip_option, into), default)]
+    name: Option<String>,
+    /// The user's nickname.
+    #[builder(setter(strip_option, into), default)]
+    nickname: Option<String>,
+    /// A URI pointing to the user's picture.
+    #[builder(setter(strip_option, into), default)]
+    picture: Option<String>,
+    /// The external user's id provided by the identity provider.
+    #[builder(setter(strip_option, into), default)]
+    user_id: Option<String>,
+    /// Initial password for this user, only valid for auth0 connection strategy.
+    #[builder(setter(strip_option, into), default)]
+    password: Option<String>,
+    /// Whether the user will receive a verification email after creation.
+    #[builder(setter(strip_option), default)]
+    verify_email: Option<bool>,
+    /// The user's username, only valid if the connection requires a username.
+    #[builder(setter(strip_option, into), default)]
+    username: Option<String>,
+}
+
+/// Response for [`CreateUser`].
+pub type CreateUserResponse = models::User;
+
+impl CreateUserBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<CreateUserResponse> {
+        let request = self.build()?;
+        request.api.http_post(USERS_ENDPOINT, &request).await
+    }
+}
+
+/// Update a user.
+///
+/// Metadata fields are merged with the existing ones: properties set to `null` are removed, while
+/// the others are added or replaced.
+#[serde_as]
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct UpdateUser {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the user to update.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Whether this user was blocked by an administrator.
+    #[builder(setter(strip_option), default)]
+    blocked: Option<bool>,
+    /// Whether this email address is verified.
+    #[builder(setter(strip_option), default)]
+    email_verified: Option<bool>,
+    /// Email address of this user.
+    #[builder(setter(strip_option, into), default)]
+    email: Option<String>,
+    /// The user's phone number (following the E.164 recommendation).
+    #[builder(setter(strip_option, into), default)]
+    phone_number: Option<String>,
+    /// Whether this phone number has been verified.
+    #[builder(setter(strip_option), default)]
+    phone_verified: Option<bool>,
+    /// User metadata to which this user has read/write access.
+    #[builder(setter(strip_option), default)]
+    user_metadata: Option<JsonValue>,
+    /// User metadata to which this user has read-only access.
+    #[builder(setter(strip_option), default)]
+    app_metadata: Option<JsonValue>,
+    /// Given name/first name/forename of this user.
+    #[builder(setter(strip_option, into), default)]
+    given_name: Option<String>,
+    /// Family name/last name/surname of this user.
+    #[builder(setter(strip_option, into), default)]
+    family_name: Option<String>,
+    /// Name of this user.
+    #[builder(setter(strip_option, into), default)]
+    name: Option<String>,
+    /// Preferred nickname or alias of this user.
+    #[builder(setter(strip_option, into), default)]
+    nickname: Option<String>,
+    /// URL to picture, photo, or avatar of this user.
+    #[builder(setter(strip_option, into), default)]
+    picture: Option<String>,
+    /// Whether this user will receive a verification email after creation.
+    #[builder(setter(strip_option), default)]
+    verify_email: Option<bool>,
+    /// Whether this user will receive a text after changing the phone number.
+    #[builder(setter(strip_option), default)]
+    verify_phone_number: Option<bool>,
+    /// New password for this user, only valid for database connections.
+    #[builder(setter(strip_option, into), default)]
+    password: Option<String>,
+    /// Name of the connection to target for this user update.
+    #[builder(setter(strip_option, into), default)]
+    connection: Option<String>,
+    /// Auth0 client ID, only valid when updating email address.
+    #[builder(setter(strip_option, into), default)]
+    client_id: Option<String>,
+    /// The user's username, only valid if the connection requires a username.
+    #[builder(setter(strip_option, into), default)]
+    username: Option<String>,
+}
+
+/// Response for [`UpdateUser`].
+pub type UpdateUserResponse = models::User;
+
+impl UpdateUserBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<UpdateUserResponse> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", USERS_ENDPOINT, path_segment(&request.id));
+        request.api.http_patch(&endpoint, &request).await
+    }
+}
+
+/// Delete a user.
+#[derive(Builder, Debug)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct DeleteUser {
+    #[builder(private)]
+    api: ManagementApi,
+    /// ID of the user to delete.
+    #[builder(private, setter(into))]
+    id: String,
+}
+
+impl DeleteUserBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}", USERS_ENDPOINT, path_segment(&request.id));
+        request.api.http_delete(&endpoint).await
+    }
+}
+
+/// Retrieve the roles of a user.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListUserRoles {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the user to list the roles of.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Page index of the results to return. First page is 0.
+    #[builder(setter(strip_option), default)]
+    page: Option<usize>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(setter(strip_option), default)]
+    per_page: Option<usize>,
+    /// Return results inside an object that contains the total result count. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    include_totals: Option<bool>,
+}
+
+/// Response for [`ListUserRoles`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListUserRolesResponse {
+    /// List of roles.
+    pub roles: Vec<models::Role>,
+    /// Page offset.
+    pub start: Option<usize>,
+    /// Maximum number of items per page.
+    pub limit: Option<usize>,
+    /// Number of items per page.
+    pub length: Option<usize>,
+    /// Total number of elements.
+    pub total: Option<usize>,
+}
+
+impl ListUserRoles {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListUserRolesResponse> {
+        let endpoint = format!("{}/{}/roles", USERS_ENDPOINT, path_segment(&self.id));
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(&endpoint, self).await
+        } else {
+            let roles = self.api.http_get(&endpoint, self).await?;
+            Ok(ListUserRolesResponse {
+                start: None,
+                limit: None,
+                length: None,
+                total: None,
+                roles,
+            })
+        }
+    }
+}
+
+impl Paginated for ListUserRoles {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListUserRolesBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListUserRolesResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::Role> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.roles,
+                total: response.total,
+            })
+        })
+    }
+}
+
+/// Assign roles to a user.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct AssignUserRoles {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the user to assign the roles to.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// IDs of the roles to assign.
+    #[builder(setter(custom), default)]
+    roles: Vec<String>,
+}
+
+impl AssignUserRolesBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}/roles", USERS_ENDPOINT, path_segment(&request.id));
+        request.api.http_post_no_content(&endpoint, &request).await
+    }
+
+    /// Append one element to the list of roles.
+    pub fn role<T: Into<String>>(&mut self, role: T) -> &mut Self {
+        self.roles.get_or_insert_with(Vec::new).push(role.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of roles.
+    pub fn roles<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.roles
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Remove roles from a user.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct RemoveUserRoles {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the user to remove the roles from.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// IDs of the roles to remove.
+    #[builder(setter(custom), default)]
+    roles: Vec<String>,
+}
+
+impl RemoveUserRolesBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!("{}/{}/roles", USERS_ENDPOINT, path_segment(&request.id));
+        request.api.http_delete_with_body(&endpoint, &request).await
+    }
+
+    /// Append one element to the list of roles.
+    pub fn role<T: Into<String>>(&mut self, role: T) -> &mut Self {
+        self.roles.get_or_insert_with(Vec::new).push(role.into());
+        self
+    }
+
+    /// Append the contents of iterator to the list of roles.
+    pub fn roles<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.roles
+            .get_or_insert_with(Vec::new)
+            .extend(iter.into_iter().map(Into::into));
+        self
+    }
+}
+
+/// Retrieve the permissions of a user.
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Builder, Clone, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct ListUserPermissions {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the user to list the permissions of.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Page index of the results to return. First page is 0.
+    #[builder(setter(strip_option), default)]
+    page: Option<usize>,
+    /// Number of results per page. Defaults to 50.
+    #[builder(setter(strip_option), default)]
+    per_page: Option<usize>,
+    /// Return results inside an object that contains the total result count. Default is `false`.
+    #[builder(setter(strip_option), default)]
+    include_totals: Option<bool>,
+}
+
+/// Response for [`ListUserPermissions`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListUserPermissionsResponse {
+    /// List of permissions, with their sources.
+    pub permissions: Vec<models::Permission>,
+    /// Page offset.
+    pub start: Option<usize>,
+    /// Maximum number of items per page.
+    pub limit: Option<usize>,
+    /// Number of items per page.
+    pub length: Option<usize>,
+    /// Total number of elements.
+    pub total: Option<usize>,
+}
+
+impl ListUserPermissions {
+    /// Send the API request.
+    async fn send(&self) -> Result<ListUserPermissionsResponse> {
+        let endpoint = format!("{}/{}/permissions", USERS_ENDPOINT, path_segment(&self.id));
+        if self.include_totals.unwrap_or(false) {
+            self.api.http_get(&endpoint, self).await
+        } else {
+            let permissions = self.api.http_get(&endpoint, self).await?;
+            Ok(ListUserPermissionsResponse {
+                start: None,
+                limit: None,
+                length: None,
+                total: None,
+                permissions,
+            })
+        }
+    }
+}
+
+impl Paginated for ListUserPermissions {
+    fn pagination(&mut self) -> Pagination<'_> {
+        Pagination {
+            page: &mut self.page,
+            per_page: &mut self.per_page,
+            include_totals: &mut self.include_totals,
+        }
+    }
+}
+
+impl ListUserPermissionsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<ListUserPermissionsResponse> {
+        self.build()?.send().await
+    }
+
+    /// Returns a stream walking lazily through all the pages of results, starting from `page`.
+    pub fn into_stream(&self) -> PageStream<models::Permission> {
+        PageStream::from_request(self.build(), |request| async move {
+            let response = request.send().await?;
+            Ok(Page {
+                items: response.permissions,
+                total: response.total,
+            })
+        })
+    }
+}
+
+/// Assign permissions to a user.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct AssignUserPermissions {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the user to assign the permissions to.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Permissions to assign.
+    #[builder(setter(custom), default)]
+    permissions: Vec<models::Permission>,
+}
+
+impl AssignUserPermissionsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/permissions",
+            USERS_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request.api.http_post_no_content(&endpoint, &request).await
+    }
+
+    /// Append a permission of an API (resource server) to the list of permissions.
+    pub fn permission<T, U>(
+        &mut self,
+        resource_server_identifier: T,
+        permission_name: U,
+    ) -> &mut Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.permissions
+            .get_or_insert_with(Vec::new)
+            .push(models::Permission::new(
+                resource_server_identifier,
+                permission_name,
+            ));
+        self
+    }
+
+    /// Append the contents of iterator to the list of permissions.
+    pub fn permissions<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = models::Permission>,
+    {
+        self.permissions.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+}
+
+/// Remove permissions from a user.
+#[derive(Builder, Debug, Serialize)]
+#[builder(build_fn(private, error = "crate::Error"))]
+pub struct RemoveUserPermissions {
+    #[builder(private)]
+    #[serde(skip)]
+    api: ManagementApi,
+    /// ID of the user to remove the permissions from.
+    #[builder(private, setter(into))]
+    #[serde(skip)]
+    id: String,
+    /// Permissions to remove.
+    #[builder(setter(custom), default)]
+    permissions: Vec<models::Permission>,
+}
+
+impl RemoveUserPermissionsBuilder {
+    /// Send the API request.
+    pub async fn send(&self) -> Result<()> {
+        let request = self.build()?;
+        let endpoint = format!(
+            "{}/{}/permissions",
+            USERS_ENDPOINT,
+            path_segment(&request.id)
+        );
+        request.api.http_delete_with_body(&endpoint, &request).await
+    }
+
+    /// Append a permission of an API (resource server) to the list of permissions.
+    pub fn permission<T, U>(
+        &mut self,
+        resource_server_identifier: T,
+        permission_name: U,
+    ) -> &mut Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.permissions
+            .get_or_insert_with(Vec::new)
+            .push(models::Permission::new(
+                resource_server_identifier,
+                permission_name,
+            ));
+        self
+    }
+
+    /// Append the contents of iterator to the list of permissions.
+    pub fn permissions<I>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = models::Permission>,
+    {
+        self.permissions.get_or_insert_with(Vec::new).extend(iter);
+        self
+    }
+}
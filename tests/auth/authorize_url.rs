@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use auth0_sdk::{auth::Pkce, AuthenticationApi};
+use claym::*;
+
+#[test]
+fn should_build_authorize_url() {
+    let auth = assert_ok!(AuthenticationApi::new(
+        "https://domain.auth0.com",
+        "xxxyyyzzz"
+    ));
+    let url = assert_ok!(auth
+        .authorize_url()
+        .redirect_uri("https://example.com/callback?from=auth0")
+        .scopes(["openid", "profile", "offline_access"])
+        .audience("https://api.example.com")
+        .state("~~state~~")
+        .nonce("~~nonce~~")
+        .organization("org_xxxyyyzzz")
+        .screen_hint("signup")
+        .url());
+
+    assert_eq!(
+        url.origin().unicode_serialization(),
+        "https://domain.auth0.com"
+    );
+    assert_eq!(url.path(), "/authorize");
+    let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+    assert_eq!(
+        query,
+        HashMap::from([
+            ("response_type".into(), "code".into()),
+            ("client_id".into(), "xxxyyyzzz".into()),
+            (
+                "redirect_uri".into(),
+                "https://example.com/callback?from=auth0".into()
+            ),
+            ("scope".into(), "openid profile offline_access".into()),
+            ("audience".into(), "https://api.example.com".into()),
+            ("state".into(), "~~state~~".into()),
+            ("nonce".into(), "~~nonce~~".into()),
+            ("organization".into(), "org_xxxyyyzzz".into()),
+            ("screen_hint".into(), "signup".into()),
+        ])
+    );
+}
+
+#[test]
+fn should_build_authorize_url_with_pkce() {
+    let auth = assert_ok!(AuthenticationApi::new(
+        "https://domain.auth0.com",
+        "xxxyyyzzz"
+    ));
+    let pkce = Pkce::new();
+    let url = assert_ok!(auth
+        .authorize_url()
+        .redirect_uri("https://example.com/callback")
+        .pkce(&pkce)
+        .url());
+
+    let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+    assert_some_eq!(query.get("code_challenge"), pkce.challenge());
+    assert_some_eq!(query.get("code_challenge_method"), "S256");
+}
+
+#[test]
+fn should_generate_pkce_challenge() {
+    let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mJ92K9A3hqzvhE3ywGmWjFhTR9IdJ7Nc");
+    assert_eq!(
+        pkce.challenge(),
+        "j68mahTO6Ma_YwH8QCWob3Bm0wmlYw51y4BSKUGPZ5M"
+    );
+
+    let pkce = Pkce::new();
+    assert_eq!(pkce.verifier().len(), 43);
+    assert_ne!(pkce.verifier(), Pkce::new().verifier());
+}
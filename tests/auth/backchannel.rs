@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use auth0_sdk::{error::ErrorCode, models::BackchannelAuthorization, AuthenticationApi};
+use claym::*;
+use serde_json::{json, Value as JsonValue};
+use wiremock::matchers;
+
+use crate::mock::*;
+
+fn backchannel_authorization() -> BackchannelAuthorization {
+    serde_json::from_value(json!({
+        "auth_req_id": "eyJh...9-ag",
+        "expires_in": 300,
+        "interval": 5,
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn should_start_backchannel_authentication() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_backchannel_authorize()
+        .respond_with(response_auth_backchannel_authorize())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ));
+    let response = assert_ok!(
+        auth.backchannel_authorize("auth0|5457edea1b8f22891a000004", "Approve transfer 1234")
+            .scopes(["profile", "offline_access"])
+            .audience("https://api.example.com")
+            .requested_expiry(120)
+            .send()
+            .await
+    );
+    assert_eq!(response.auth_req_id, "eyJh...9-ag");
+    assert_eq!(response.expires_in, 300);
+    assert_eq!(response.interval, 5);
+
+    let requests = assert_some!(mock.received_requests().await);
+    let mut form: HashMap<String, String> =
+        assert_ok!(serde_urlencoded::from_bytes(&requests[0].body));
+    let login_hint: JsonValue = assert_ok!(serde_json::from_str(&assert_some!(
+        form.remove("login_hint")
+    )));
+    assert_eq!(
+        login_hint,
+        json!({
+            "format": "iss_sub",
+            "iss": format!("{}/", mock.domain()),
+            "sub": "auth0|5457edea1b8f22891a000004",
+        })
+    );
+    assert_eq!(
+        form,
+        HashMap::from([
+            ("client_id".into(), client_id.into()),
+            ("client_secret".into(), client_secret.into()),
+            ("binding_message".into(), "Approve transfer 1234".into()),
+            ("scope".into(), "openid profile offline_access".into()),
+            ("audience".into(), "https://api.example.com".into()),
+            ("requested_expiry".into(), "120".into()),
+        ])
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn should_poll_backchannel_token_until_approved() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+    let authorization = backchannel_authorization();
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_json(json!({
+            "grant_type": "urn:openid:params:grant-type:ciba",
+            "auth_req_id": &authorization.auth_req_id,
+            "client_id": &client_id,
+            "client_secret": &client_secret,
+        })))
+        .respond_with(response_auth_error(
+            400,
+            "authorization_pending",
+            "The end-user authorization is pending",
+        ))
+        .up_to_n_times(1)
+        .mount(&mock)
+        .await;
+    matcher_get_token()
+        .respond_with(response_auth_error(
+            429,
+            "slow_down",
+            "You are polling faster than allowed. Try again in 10 seconds.",
+        ))
+        .up_to_n_times(1)
+        .mount(&mock)
+        .await;
+    matcher_get_token()
+        .respond_with(response_auth_tokens())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ));
+    let response = assert_ok!(auth.poll_backchannel_token(&authorization).await);
+    assert!(!response.access_token.is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn fail_to_poll_backchannel_token_when_rejected() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .respond_with(response_auth_error(
+            400,
+            "access_denied",
+            "The end-user denied the authorization request or it has been expired",
+        ))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        "secret_of_xxxyyyzzz"
+    ));
+    let error = assert_err!(
+        auth.poll_backchannel_token(&backchannel_authorization())
+            .await
+    );
+    assert_some_eq!(error.code(), &ErrorCode::AccessDenied);
+}
@@ -0,0 +1,106 @@
+use auth0_sdk::{error::ErrorCode, AuthenticationApi};
+use claym::*;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
+
+use crate::mock::*;
+
+#[tokio::test]
+async fn should_signup_user() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_signup()
+        .and(matchers::body_json(json!({
+            "client_id": &client_id,
+            "email": "john.doe@gmail.com",
+            "password": "secret",
+            "connection": "Username-Password-Authentication",
+            "username": "johndoe",
+            "given_name": "John",
+            "family_name": "Doe",
+            "user_metadata": { "plan": "silver" },
+        })))
+        .respond_with(response_auth_signup())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let user = assert_ok!(
+        auth.signup(
+            "john.doe@gmail.com",
+            "secret",
+            "Username-Password-Authentication"
+        )
+        .username("johndoe")
+        .given_name("John")
+        .family_name("Doe")
+        .user_metadata(json!({ "plan": "silver" }))
+        .send()
+        .await
+    );
+
+    assert_eq!(user.id, "58457fe6b27d8c1a5d7a1c3b");
+    assert_eq!(user.email, "john.doe@gmail.com");
+    assert!(!user.email_verified);
+    assert_some_eq!(user.username.as_deref(), "johndoe");
+}
+
+#[tokio::test]
+async fn fail_to_signup_existing_user() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_signup()
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "name": "BadRequestError",
+            "code": "invalid_signup",
+            "description": "Invalid sign up",
+            "statusCode": 400,
+        })))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(
+        auth.signup(
+            "john.doe@gmail.com",
+            "secret",
+            "Username-Password-Authentication"
+        )
+        .send()
+        .await
+    );
+    assert_some_eq!(
+        error.code(),
+        &ErrorCode::Other("invalid_signup".to_string())
+    );
+}
+
+#[tokio::test]
+async fn should_change_password() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_change_password()
+        .and(matchers::body_json(json!({
+            "client_id": &client_id,
+            "email": "john.doe@gmail.com",
+            "connection": "Username-Password-Authentication",
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string("We've just sent you an email to reset your password."),
+        )
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    assert_ok!(
+        auth.change_password("john.doe@gmail.com", "Username-Password-Authentication")
+            .send()
+            .await
+    );
+}
@@ -0,0 +1,197 @@
+use auth0_sdk::AuthenticationApi;
+use claym::*;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde_json::json;
+use time::OffsetDateTime;
+use wiremock::{matchers, Mock, ResponseTemplate};
+
+use crate::mock::*;
+
+fn discovery_document(base: &str) -> serde_json::Value {
+    json!({
+        "issuer": format!("{base}/"),
+        "authorization_endpoint": format!("{base}/custom/authorize"),
+        "token_endpoint": format!("{base}/custom/oauth/token"),
+        "jwks_uri": format!("{base}/custom/jwks.json"),
+        "userinfo_endpoint": format!("{base}/custom/userinfo"),
+        "scopes_supported": ["openid", "profile", "offline_access"],
+        "response_types_supported": ["code", "token", "id_token"],
+        "id_token_signing_alg_values_supported": ["HS256", "RS256", "PS256"],
+        "request_parameter_supported": false,
+    })
+}
+
+#[tokio::test]
+async fn should_discover_openid_configuration() {
+    let mock = MockApi::new().await;
+    matcher_discovery()
+        .respond_with(ResponseTemplate::new(200).set_body_json(discovery_document(&mock.domain())))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), "xxxyyyzzz"));
+    let config = assert_ok!(auth.discover().await);
+
+    assert_eq!(
+        config.token_endpoint,
+        format!("{}/custom/oauth/token", mock.domain())
+    );
+    assert_eq!(
+        config.scopes_supported,
+        ["openid", "profile", "offline_access"]
+    );
+    assert_eq!(
+        config.id_token_signing_alg_values_supported,
+        ["HS256", "RS256", "PS256"]
+    );
+    assert_some_eq!(
+        config.values.get("request_parameter_supported"),
+        &json!(false)
+    );
+
+    // The document is cached.
+    assert_ok!(auth.discover().await);
+}
+
+#[tokio::test]
+async fn should_use_discovered_endpoints() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_discovery()
+        .respond_with(ResponseTemplate::new(200).set_body_json(discovery_document(&mock.domain())))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path("/custom/oauth/token"))
+        .respond_with(response_auth_tokens())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ))
+    .with_discovery();
+    let response = assert_ok!(
+        assert_ok!(auth.get_token("https://api.example.com"))
+            .send()
+            .await
+    );
+    assert!(!response.access_token.is_empty());
+
+    let url = assert_ok!(auth.authorize_url().url());
+    assert_eq!(url.path(), "/custom/authorize");
+}
+
+#[tokio::test]
+async fn should_use_default_endpoints_without_discovery() {
+    let mock = MockApi::new().await;
+    matcher_discovery()
+        .respond_with(ResponseTemplate::new(200).set_body_json(discovery_document(&mock.domain())))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), "xxxyyyzzz"));
+    assert_ok!(auth.discover().await);
+
+    let url = assert_ok!(auth.authorize_url().url());
+    assert_eq!(url.path(), "/authorize");
+}
+
+#[tokio::test]
+async fn should_verify_token_with_discovered_issuer() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+    let issuer = "https://login.example.com/";
+
+    let mock = MockApi::new().await;
+    let mut document = discovery_document(&mock.domain());
+    document["issuer"] = json!(issuer);
+    matcher_discovery()
+        .respond_with(ResponseTemplate::new(200).set_body_json(document))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ))
+    .with_discovery();
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    let sign = |iss: String| {
+        let claims = json!({
+            "iss": iss,
+            "sub": "auth0|xxxyyyzz",
+            "aud": client_id,
+            "iat": now,
+            "exp": now + 3600,
+        });
+        let key = EncodingKey::from_secret(client_secret.as_bytes());
+        jsonwebtoken::encode(&Header::new(Algorithm::HS256), &claims, &key).unwrap()
+    };
+
+    let claims = assert_ok!(
+        auth.verify_id_token(sign(issuer.to_string()))
+            .algorithms(vec![Algorithm::HS256])
+            .verify()
+            .await
+    );
+    assert_eq!(claims.iss, issuer);
+
+    // The tenant's domain is not accepted once the issuer is discovered.
+    assert_err!(
+        auth.verify_id_token(sign(format!("{}/", mock.domain())))
+            .algorithms(vec![Algorithm::HS256])
+            .verify()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_use_discovered_issuer_in_backchannel_login_hint() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+    let issuer = "https://login.example.com/";
+
+    let mock = MockApi::new().await;
+    let mut document = discovery_document(&mock.domain());
+    document["issuer"] = json!(issuer);
+    matcher_discovery()
+        .respond_with(ResponseTemplate::new(200).set_body_json(document))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    let login_hint = json!({
+        "format": "iss_sub",
+        "iss": issuer,
+        "sub": "auth0|5457edea1b8f22891a000004",
+    });
+    matcher_backchannel_authorize()
+        .and(matchers::body_string_contains(
+            serde_urlencoded::to_string([("login_hint", login_hint.to_string())]).unwrap(),
+        ))
+        .respond_with(response_auth_backchannel_authorize())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ))
+    .with_discovery();
+    assert_ok!(
+        auth.backchannel_authorize("auth0|5457edea1b8f22891a000004", "Approve transfer 1234")
+            .send()
+            .await
+    );
+}
@@ -1,4 +1,4 @@
-use auth0_sdk::AuthenticationApi;
+use auth0_sdk::{error::ErrorCode, models::DeviceCode, AuthenticationApi, Error};
 use claym::*;
 use serde_json::json;
 use wiremock::matchers;
@@ -47,3 +47,106 @@ async fn get_device_code_with_custom_parameters() {
             .await
     );
 }
+
+fn device_code() -> DeviceCode {
+    serde_json::from_slice(include_bytes!("../../testdata/auth/device_code.json")).unwrap()
+}
+
+#[tokio::test(start_paused = true)]
+async fn should_poll_device_token_until_authorized() {
+    let client_id = "xxxyyyzzz";
+    let device_code = device_code();
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_json(json!({
+            "grant_type": "urn:ietf:params:oauth:grant-type:device_code",
+            "device_code": &device_code.device_code,
+            "client_id": &client_id,
+        })))
+        .respond_with(response_auth_error(
+            403,
+            "authorization_pending",
+            "User has yet to authorize device code.",
+        ))
+        .up_to_n_times(1)
+        .mount(&mock)
+        .await;
+    matcher_get_token()
+        .respond_with(response_auth_error(
+            429,
+            "slow_down",
+            "You are polling faster than the specified interval of 5 seconds.",
+        ))
+        .up_to_n_times(1)
+        .mount(&mock)
+        .await;
+    matcher_get_token()
+        .respond_with(response_auth_tokens())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let response = assert_ok!(auth.poll_device_token(&device_code).await);
+    assert!(!response.access_token.is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn fail_to_poll_device_token_when_expired() {
+    let client_id = "xxxyyyzzz";
+    let device_code = device_code();
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .respond_with(response_auth_error(
+            403,
+            "expired_token",
+            "Token has expired.",
+        ))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(auth.poll_device_token(&device_code).await);
+    assert_some_eq!(error.code(), &ErrorCode::ExpiredToken);
+}
+
+#[tokio::test(start_paused = true)]
+async fn fail_to_poll_device_token_when_denied() {
+    let client_id = "xxxyyyzzz";
+    let device_code = device_code();
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .respond_with(response_auth_error(403, "access_denied", "User cancelled the confirmation prompt or consent page; the code expired; there was an error."))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(auth.poll_device_token(&device_code).await);
+    assert_some_eq!(error.code(), &ErrorCode::AccessDenied);
+}
+
+#[tokio::test(start_paused = true)]
+async fn fail_to_poll_device_token_after_expiration() {
+    let client_id = "xxxyyyzzz";
+    let mut device_code = device_code();
+    device_code.expires_in = 12;
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .respond_with(response_auth_error(
+            403,
+            "authorization_pending",
+            "User has yet to authorize device code.",
+        ))
+        .expect(2)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(auth.poll_device_token(&device_code).await);
+    assert_matches!(error, Error::Expired);
+}
@@ -1,4 +1,4 @@
-use auth0_sdk::AuthenticationApi;
+use auth0_sdk::{error::ErrorCode, AuthenticationApi};
 use claym::*;
 use serde_json::json;
 use wiremock::matchers;
@@ -185,3 +185,55 @@ async fn should_login_with_user_password_with_custom_parameters() {
     assert!(!assert_some!(response.refresh_token).is_empty());
     assert!(!assert_some!(response.id_token).is_empty());
 }
+
+#[tokio::test]
+async fn fail_to_login_with_invalid_grant() {
+    let client_id = "xxxyyyzzz";
+
+    let username = "~~username~~";
+    let password = "~~password~~";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .respond_with(response_auth_error(
+            403,
+            "invalid_grant",
+            "Wrong email or password.",
+        ))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id,));
+    let error = assert_err!(auth.login(username, password).send().await);
+
+    assert_some_eq!(error.code(), &ErrorCode::InvalidGrant);
+    let error = assert_some!(error.api_error());
+    assert_eq!(error.status, 403);
+    assert_some_eq!(
+        error.payload.error_description.as_deref(),
+        "Wrong email or password."
+    );
+}
+
+#[tokio::test]
+async fn should_not_retry_token_request_on_server_error() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+    let audience = "https://domain.auth0.com/users";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .respond_with(response_auth_error(500, "server_error", "Internal error."))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ));
+    let request = assert_ok!(auth.get_token(audience));
+    let error = assert_err!(request.send().await);
+    assert_some_eq!(error.code(), &ErrorCode::ServerError);
+}
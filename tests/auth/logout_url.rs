@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use auth0_sdk::AuthenticationApi;
+use claym::*;
+
+#[test]
+fn should_build_logout_url() {
+    let auth = assert_ok!(AuthenticationApi::new(
+        "https://domain.auth0.com",
+        "xxxyyyzzz"
+    ));
+    let url = assert_ok!(auth
+        .logout_url()
+        .return_to("https://example.com/logged-out?reason=user request")
+        .url());
+
+    assert_eq!(
+        url.origin().unicode_serialization(),
+        "https://domain.auth0.com"
+    );
+    assert_eq!(url.path(), "/v2/logout");
+    assert_eq!(
+        url.query(),
+        Some("returnTo=https%3A%2F%2Fexample.com%2Flogged-out%3Freason%3Duser+request&client_id=xxxyyyzzz")
+    );
+}
+
+#[test]
+fn should_build_federated_logout_url() {
+    let auth = assert_ok!(AuthenticationApi::new(
+        "https://domain.auth0.com",
+        "xxxyyyzzz"
+    ));
+    let url = assert_ok!(auth.logout_url().federated(true).url());
+
+    assert_eq!(url.path(), "/v2/logout");
+    assert_eq!(url.query(), Some("client_id=xxxyyyzzz&federated"));
+}
+
+#[test]
+fn should_build_oidc_logout_url() {
+    let auth = assert_ok!(AuthenticationApi::new(
+        "https://domain.auth0.com",
+        "xxxyyyzzz"
+    ));
+    let url = assert_ok!(auth
+        .oidc_logout_url()
+        .id_token_hint("eyJhbGciOi...")
+        .logout_hint("~~sid~~")
+        .post_logout_redirect_uri("https://example.com/logged-out")
+        .state("a&b=c")
+        .ui_locales("fr-CA fr en")
+        .url());
+
+    assert_eq!(url.path(), "/oidc/logout");
+    let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+    assert_eq!(
+        query,
+        HashMap::from([
+            ("id_token_hint".into(), "eyJhbGciOi...".into()),
+            ("logout_hint".into(), "~~sid~~".into()),
+            (
+                "post_logout_redirect_uri".into(),
+                "https://example.com/logged-out".into()
+            ),
+            ("state".into(), "a&b=c".into()),
+            ("ui_locales".into(), "fr-CA fr en".into()),
+            ("client_id".into(), "xxxyyyzzz".into()),
+        ])
+    );
+}
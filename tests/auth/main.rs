@@ -1,3 +1,17 @@
+mod authorize_url;
+mod backchannel;
+mod dbconnections;
+mod discovery;
 mod get_device_code;
 mod get_token;
+mod logout_url;
+mod mfa;
 mod mock;
+mod par;
+mod passwordless;
+mod private_key_jwt;
+mod refresh_token;
+mod revoke_token;
+mod token_exchange;
+mod user_info;
+mod verify_token;
@@ -0,0 +1,281 @@
+use auth0_sdk::{error::ErrorCode, AuthenticationApi};
+use claym::*;
+use http::header;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
+
+use crate::mock::*;
+
+const MFA_TOKEN: &str = "Fe26.2*SERVER_1570...";
+
+#[tokio::test]
+async fn should_expose_mfa_token_of_failed_login() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "error": "mfa_required",
+            "error_description": "Multifactor authentication required",
+            "mfa_token": MFA_TOKEN,
+        })))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(auth.login("john.doe", "secret").send().await);
+
+    assert_some_eq!(error.code(), &ErrorCode::MfaRequired);
+    assert_some_eq!(error.mfa_token(), MFA_TOKEN);
+}
+
+#[tokio::test]
+async fn should_request_mfa_challenge() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_mfa_challenge()
+        .and(matchers::body_json(json!({
+            "client_id": &client_id,
+            "mfa_token": MFA_TOKEN,
+            "challenge_type": "otp oob",
+            "authenticator_id": "sms|dev_aaabbbccc",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "challenge_type": "oob",
+            "oob_code": "asdae35fdt5...oob_code_redacted",
+            "binding_method": "prompt",
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let challenge = assert_ok!(
+        auth.mfa(MFA_TOKEN)
+            .challenge()
+            .challenge_types(["otp", "oob"])
+            .authenticator_id("sms|dev_aaabbbccc")
+            .send()
+            .await
+    );
+
+    assert_eq!(challenge.challenge_type, "oob");
+    assert_some!(challenge.oob_code);
+    assert_some_eq!(challenge.binding_method.as_deref(), "prompt");
+}
+
+#[tokio::test]
+async fn should_associate_mfa_authenticator() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_mfa_associate()
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {MFA_TOKEN}"),
+        ))
+        .and(matchers::body_json(json!({
+            "client_id": &client_id,
+            "authenticator_types": ["otp"],
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "authenticator_type": "otp",
+            "secret": "EN...S",
+            "barcode_uri": "otpauth://totp/tenant:user?secret=...&issuer=tenant&algorithm=SHA1&digits=6&period=30",
+            "recovery_codes": ["N3BGPZZWJ85JLCNPZBDW6QXC"],
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let association = assert_ok!(
+        auth.mfa(MFA_TOKEN)
+            .associate()
+            .authenticator_type("otp")
+            .send()
+            .await
+    );
+
+    assert_eq!(association.authenticator_type, "otp");
+    assert_some!(association.barcode_uri);
+    assert_eq!(association.recovery_codes.len(), 1);
+}
+
+#[tokio::test]
+async fn should_associate_mfa_oob_authenticator() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_mfa_associate()
+        .and(matchers::body_json(json!({
+            "client_id": &client_id,
+            "authenticator_types": ["oob"],
+            "oob_channels": ["sms", "voice"],
+            "phone_number": "+15555555555",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "authenticator_type": "oob",
+            "oob_channel": "sms",
+            "oob_code": "Fe26...Ha",
+            "binding_method": "prompt",
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let association = assert_ok!(
+        auth.mfa(MFA_TOKEN)
+            .associate()
+            .authenticator_types(["oob"])
+            .oob_channels(["sms", "voice"])
+            .phone_number("+15555555555")
+            .send()
+            .await
+    );
+
+    assert_eq!(association.authenticator_type, "oob");
+}
+
+#[tokio::test]
+async fn should_list_and_delete_mfa_authenticators() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_mfa_authenticators()
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {MFA_TOKEN}"),
+        ))
+        .respond_with(response_auth_mfa_authenticators())
+        .expect(1)
+        .mount(&mock)
+        .await;
+    matcher_mfa_delete_authenticator()
+        .and(matchers::path("/mfa/authenticators/sms%7Cdev_aaabbbccc"))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {MFA_TOKEN}"),
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let mfa = auth.mfa(MFA_TOKEN);
+    let authenticators = assert_ok!(mfa.authenticators().await);
+
+    assert_eq!(authenticators.len(), 2);
+    assert_eq!(authenticators[1].id, "sms|dev_aaabbbccc");
+    assert_some_eq!(authenticators[1].oob_channel.as_deref(), "sms");
+
+    assert_ok!(mfa.delete_authenticator(&authenticators[1].id).await);
+}
+
+#[tokio::test]
+async fn should_encode_mfa_authenticator_id() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_mfa_delete_authenticator()
+        .and(matchers::path("/mfa/authenticators/sms%2F..%2Fdev%3Faaa"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let mfa = auth.mfa(MFA_TOKEN);
+    assert_ok!(mfa.delete_authenticator("sms/../dev?aaa").await);
+}
+
+#[tokio::test]
+async fn should_verify_mfa_otp() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_json(json!({
+            "grant_type": "http://auth0.com/oauth/grant-type/mfa-otp",
+            "client_id": &client_id,
+            "client_secret": &client_secret,
+            "mfa_token": MFA_TOKEN,
+            "otp": "123456",
+        })))
+        .respond_with(response_auth_tokens())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ));
+    let response = assert_ok!(auth.mfa(MFA_TOKEN).verify_otp("123456").send().await);
+    assert!(!response.access_token.is_empty());
+}
+
+#[tokio::test]
+async fn should_verify_mfa_oob() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_json(json!({
+            "grant_type": "http://auth0.com/oauth/grant-type/mfa-oob",
+            "client_id": &client_id,
+            "mfa_token": MFA_TOKEN,
+            "oob_code": "asdae35fdt5",
+            "binding_code": "000000",
+        })))
+        .respond_with(response_auth_tokens())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let response = assert_ok!(
+        auth.mfa(MFA_TOKEN)
+            .verify_oob("asdae35fdt5")
+            .binding_code("000000")
+            .send()
+            .await
+    );
+    assert!(!response.access_token.is_empty());
+}
+
+#[tokio::test]
+async fn fail_to_verify_invalid_mfa_recovery_code() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_json(json!({
+            "grant_type": "http://auth0.com/oauth/grant-type/mfa-recovery-code",
+            "client_id": &client_id,
+            "mfa_token": MFA_TOKEN,
+            "recovery_code": "WRONGCODE",
+        })))
+        .respond_with(response_auth_error(
+            403,
+            "invalid_grant",
+            "Invalid recovery_code.",
+        ))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(
+        auth.mfa(MFA_TOKEN)
+            .verify_recovery_code("WRONGCODE")
+            .send()
+            .await
+    );
+    assert_some_eq!(error.code(), &ErrorCode::InvalidGrant);
+}
@@ -1,5 +1,6 @@
 use std::ops::Deref;
 
+use serde_json::json;
 use wiremock::{matchers, Mock, MockBuilder, MockServer, ResponseTemplate};
 
 pub struct MockApi {
@@ -36,6 +37,58 @@ pub fn matcher_get_device_code() -> MockBuilder {
     Mock::given(matchers::method("POST")).and(matchers::path("/oauth/device/code"))
 }
 
+pub fn matcher_signup() -> MockBuilder {
+    Mock::given(matchers::method("POST")).and(matchers::path("/dbconnections/signup"))
+}
+
+pub fn matcher_change_password() -> MockBuilder {
+    Mock::given(matchers::method("POST")).and(matchers::path("/dbconnections/change_password"))
+}
+
+pub fn matcher_mfa_challenge() -> MockBuilder {
+    Mock::given(matchers::method("POST")).and(matchers::path("/mfa/challenge"))
+}
+
+pub fn matcher_mfa_associate() -> MockBuilder {
+    Mock::given(matchers::method("POST")).and(matchers::path("/mfa/associate"))
+}
+
+pub fn matcher_mfa_authenticators() -> MockBuilder {
+    Mock::given(matchers::method("GET")).and(matchers::path("/mfa/authenticators"))
+}
+
+pub fn matcher_mfa_delete_authenticator() -> MockBuilder {
+    Mock::given(matchers::method("DELETE")).and(matchers::path_regex("^/mfa/authenticators/.+$"))
+}
+
+pub fn matcher_passwordless_start() -> MockBuilder {
+    Mock::given(matchers::method("POST")).and(matchers::path("/passwordless/start"))
+}
+
+pub fn matcher_backchannel_authorize() -> MockBuilder {
+    Mock::given(matchers::method("POST")).and(matchers::path("/bc-authorize"))
+}
+
+pub fn matcher_par() -> MockBuilder {
+    Mock::given(matchers::method("POST")).and(matchers::path("/oauth/par"))
+}
+
+pub fn matcher_revoke_token() -> MockBuilder {
+    Mock::given(matchers::method("POST")).and(matchers::path("/oauth/revoke"))
+}
+
+pub fn matcher_get_user_info() -> MockBuilder {
+    Mock::given(matchers::method("GET")).and(matchers::path("/userinfo"))
+}
+
+pub fn matcher_discovery() -> MockBuilder {
+    Mock::given(matchers::method("GET")).and(matchers::path("/.well-known/openid-configuration"))
+}
+
+pub fn matcher_get_jwks() -> MockBuilder {
+    Mock::given(matchers::method("GET")).and(matchers::path("/.well-known/jwks.json"))
+}
+
 // responses
 // ----------------------------------------------------------------------------
 pub fn response_auth_tokens() -> ResponseTemplate {
@@ -48,6 +101,53 @@ pub fn response_auth_device_code() -> ResponseTemplate {
     json_response_template(BODY)
 }
 
+pub fn response_auth_signup() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/auth/signup.json");
+    json_response_template(BODY)
+}
+
+pub fn response_auth_mfa_authenticators() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/auth/mfa_authenticators.json");
+    json_response_template(BODY)
+}
+
+pub fn response_auth_passwordless_start() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/auth/passwordless_start.json");
+    json_response_template(BODY)
+}
+
+pub fn response_auth_backchannel_authorize() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "auth_req_id": "eyJh...9-ag",
+        "expires_in": 300,
+        "interval": 5,
+    }))
+}
+
+pub fn response_auth_par() -> ResponseTemplate {
+    ResponseTemplate::new(201).set_body_json(json!({
+        "request_uri": "urn:ietf:params:oauth:request_uri:6esc_11ACC5bwc014ltc14eY22c",
+        "expires_in": 30,
+    }))
+}
+
+pub fn response_auth_user_info() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/auth/user_info.json");
+    json_response_template(BODY)
+}
+
+pub fn response_auth_jwks() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/auth/jwks.json");
+    json_response_template(BODY)
+}
+
+pub fn response_auth_error(status: u16, error: &str, description: &str) -> ResponseTemplate {
+    ResponseTemplate::new(status).set_body_json(json!({
+        "error": error,
+        "error_description": description,
+    }))
+}
+
 fn json_response_template(data: &[u8]) -> ResponseTemplate {
     ResponseTemplate::new(200).set_body_raw(data, "application/json")
 }
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use auth0_sdk::{auth::Pkce, error::ErrorCode, jwt::Algorithm, AuthenticationApi};
+use claym::*;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
+
+use crate::mock::*;
+
+const PRIVATE_KEY: &[u8] = include_bytes!("../../testdata/auth/private_key.pem");
+
+#[tokio::test]
+async fn should_push_authorization_request() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+    let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+
+    let mock = MockApi::new().await;
+    matcher_par()
+        .and(matchers::header(
+            "content-type",
+            "application/x-www-form-urlencoded",
+        ))
+        .respond_with(response_auth_par())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ));
+    let response = assert_ok!(
+        auth.authorize_url()
+            .redirect_uri("https://example.com/callback")
+            .scopes(["openid", "profile"])
+            .state("~~state~~")
+            .pkce(&pkce)
+            .push()
+            .await
+    );
+    assert_eq!(
+        response.request_uri,
+        "urn:ietf:params:oauth:request_uri:6esc_11ACC5bwc014ltc14eY22c"
+    );
+    assert_eq!(response.expires_in, 30);
+
+    let requests = assert_some!(mock.received_requests().await);
+    let form: HashMap<String, String> = assert_ok!(serde_urlencoded::from_bytes(&requests[0].body));
+    assert_eq!(
+        form,
+        HashMap::from([
+            ("response_type".into(), "code".into()),
+            ("client_id".into(), client_id.into()),
+            ("client_secret".into(), client_secret.into()),
+            ("redirect_uri".into(), "https://example.com/callback".into()),
+            ("scope".into(), "openid profile".into()),
+            ("state".into(), "~~state~~".into()),
+            ("code_challenge".into(), pkce.challenge().into()),
+            ("code_challenge_method".into(), "S256".into()),
+        ])
+    );
+
+    let url = assert_ok!(auth.pushed_authorize_url(&response.request_uri));
+    assert_eq!(url.path(), "/authorize");
+    let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+    assert_eq!(
+        query,
+        HashMap::from([
+            ("client_id".into(), client_id.into()),
+            ("request_uri".into(), response.request_uri.clone()),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn should_push_authorization_request_with_private_key_jwt() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_par()
+        .and(matchers::body_string_contains(
+            "client_assertion_type=urn%3Aietf%3Aparams%3Aoauth%3Aclient-assertion-type%3Ajwt-bearer",
+        ))
+        .and(matchers::body_string_contains("client_assertion=ey"))
+        .respond_with(response_auth_par())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_private_key_jwt(
+        &mock.domain(),
+        client_id,
+        PRIVATE_KEY,
+        "test-key-1",
+        Algorithm::RS256
+    ));
+    assert_ok!(
+        auth.authorize_url()
+            .redirect_uri("https://example.com/callback")
+            .push()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn fail_to_push_authorization_request() {
+    let mock = MockApi::new().await;
+    matcher_par()
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "invalid_request",
+            "error_description": "Invalid redirect_uri",
+        })))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        "xxxyyyzzz",
+        "secret_of_xxxyyyzzz"
+    ));
+    let error = assert_err!(auth.authorize_url().push().await);
+    assert_some_eq!(error.code(), &ErrorCode::InvalidRequest);
+}
@@ -0,0 +1,151 @@
+use auth0_sdk::{
+    auth::{PasswordlessConnection, PasswordlessSend},
+    error::ErrorCode,
+    AuthenticationApi,
+};
+use claym::*;
+use serde_json::json;
+use wiremock::matchers;
+
+use crate::mock::*;
+
+#[tokio::test]
+async fn should_start_passwordless_with_email() {
+    let client_id = "xxxyyyzzz";
+    let email = "john.doe@gmail.com";
+
+    let mock = MockApi::new().await;
+    matcher_passwordless_start()
+        .and(matchers::body_json(json!({
+            "client_id": &client_id,
+            "connection": "email",
+            "email": email,
+            "send": "link",
+            "authParams": {
+                "scope": "openid profile",
+                "state": "~~state~~",
+            },
+        })))
+        .respond_with(response_auth_passwordless_start())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let response = assert_ok!(
+        auth.passwordless_start()
+            .email(email)
+            .delivery(PasswordlessSend::Link)
+            .auth_param("scope", "openid profile")
+            .auth_param("state", "~~state~~")
+            .send()
+            .await
+    );
+
+    assert_eq!(response.id, "5f7c1e2d3a4b5c6d7e8f9a0b");
+    assert_some_eq!(response.email.as_deref(), email);
+}
+
+#[tokio::test]
+async fn should_start_passwordless_with_sms() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+    let phone_number = "+391234567890";
+
+    let mock = MockApi::new().await;
+    matcher_passwordless_start()
+        .and(matchers::body_json(json!({
+            "client_id": &client_id,
+            "client_secret": &client_secret,
+            "connection": "sms",
+            "phone_number": phone_number,
+            "send": "code",
+        })))
+        .respond_with(response_auth_passwordless_start())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ));
+    assert_ok!(
+        auth.passwordless_start()
+            .phone_number(phone_number)
+            .delivery(PasswordlessSend::Code)
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn fail_to_start_passwordless_without_recipient() {
+    let auth = assert_ok!(AuthenticationApi::new(
+        "https://domain.auth0.com",
+        "xxxyyyzzz"
+    ));
+    assert_err!(auth.passwordless_start().send().await);
+}
+
+#[tokio::test]
+async fn should_login_with_passwordless_otp() {
+    let client_id = "xxxyyyzzz";
+    let phone_number = "+391234567890";
+    let otp = "123456";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_json(json!({
+            "grant_type": "http://auth0.com/oauth/grant-type/passwordless/otp",
+            "client_id": &client_id,
+            "realm": "sms",
+            "username": phone_number,
+            "otp": otp,
+            "audience": "https://api.example.com",
+            "scope": "openid offline_access",
+        })))
+        .respond_with(response_auth_tokens())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let response = assert_ok!(
+        auth.passwordless_login(PasswordlessConnection::Sms, phone_number, otp)
+            .audience("https://api.example.com")
+            .scopes(["openid", "offline_access"])
+            .send()
+            .await
+    );
+
+    assert!(!response.access_token.is_empty());
+}
+
+#[tokio::test]
+async fn fail_to_login_with_invalid_otp() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .respond_with(response_auth_error(
+            403,
+            "invalid_grant",
+            "Wrong email or verification code.",
+        ))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(
+        auth.passwordless_login(
+            PasswordlessConnection::Email,
+            "john.doe@gmail.com",
+            "000000"
+        )
+        .send()
+        .await
+    );
+    assert_some_eq!(error.code(), &ErrorCode::InvalidGrant);
+}
@@ -0,0 +1,154 @@
+use auth0_sdk::{jwt::Algorithm, AuthenticationApi, Error};
+use claym::*;
+use jsonwebtoken::{jwk::JwkSet, DecodingKey, Validation};
+use serde_json::{json, Value as JsonValue};
+use wiremock::{matchers, ResponseTemplate};
+
+use crate::mock::*;
+
+const PRIVATE_KEY: &[u8] = include_bytes!("../../testdata/auth/private_key.pem");
+const JWKS: &[u8] = include_bytes!("../../testdata/auth/jwks.json");
+const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// Decode and validate the client assertion sent in the body of the request.
+fn decode_assertion(body: &[u8], client_id: &str, audience: &str) -> JsonValue {
+    let body: JsonValue = serde_json::from_slice(body).unwrap();
+    let assertion = body["client_assertion"].as_str().unwrap();
+
+    let header = jsonwebtoken::decode_header(assertion).unwrap();
+    assert_eq!(header.alg, Algorithm::PS256);
+    assert_some_eq!(header.kid.as_deref(), "test-key-1");
+
+    let jwks: JwkSet = serde_json::from_slice(JWKS).unwrap();
+    let key = DecodingKey::from_jwk(&jwks.keys[0]).unwrap();
+    let mut validation = Validation::new(Algorithm::PS256);
+    validation.set_issuer(&[client_id]);
+    validation.set_audience(&[audience]);
+    jsonwebtoken::decode::<JsonValue>(assertion, &key, &validation)
+        .unwrap()
+        .claims
+}
+
+#[tokio::test]
+async fn should_get_token_with_private_key_jwt() {
+    let client_id = "xxxyyyzzz";
+    let audience = "https://api.example.com";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_partial_json(json!({
+            "grant_type": "client_credentials",
+            "client_id": &client_id,
+            "audience": audience,
+            "client_assertion_type": CLIENT_ASSERTION_TYPE,
+        })))
+        .respond_with(response_auth_tokens())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_private_key_jwt(
+        &mock.domain(),
+        client_id,
+        PRIVATE_KEY,
+        "test-key-1",
+        Algorithm::PS256
+    ));
+    let response = assert_ok!(assert_ok!(auth.get_token(audience)).send().await);
+    assert!(!response.access_token.is_empty());
+
+    let requests = assert_some!(mock.received_requests().await);
+    let body: JsonValue = assert_ok!(serde_json::from_slice(&requests[0].body));
+    assert_none!(body.get("client_secret"));
+
+    let claims = decode_assertion(&requests[0].body, client_id, &format!("{}/", mock.domain()));
+    assert_eq!(claims["sub"], client_id);
+    assert!(claims["jti"].as_str().is_some_and(|jti| !jti.is_empty()));
+}
+
+#[tokio::test]
+async fn should_get_token_with_auth_code_with_private_key_jwt() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_partial_json(json!({
+            "grant_type": "authorization_code",
+            "client_id": &client_id,
+            "code": "some-code",
+            "client_assertion_type": CLIENT_ASSERTION_TYPE,
+        })))
+        .respond_with(response_auth_tokens())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_private_key_jwt(
+        &mock.domain(),
+        client_id,
+        PRIVATE_KEY,
+        "test-key-1",
+        Algorithm::PS256
+    ));
+    let request = assert_ok!(auth.get_token_with_auth_code("some-code"));
+    assert_ok!(request.send().await);
+
+    let requests = assert_some!(mock.received_requests().await);
+    let body: JsonValue = assert_ok!(serde_json::from_slice(&requests[0].body));
+    assert_none!(body.get("client_secret"));
+    assert_eq!(body["client_assertion_type"], CLIENT_ASSERTION_TYPE);
+    decode_assertion(&requests[0].body, client_id, &format!("{}/", mock.domain()));
+}
+
+#[tokio::test]
+async fn should_revoke_token_with_private_key_jwt() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_revoke_token()
+        .and(matchers::body_partial_json(json!({
+            "client_id": &client_id,
+            "token": "GEbRxBN...edjnXbL",
+            "client_assertion_type": CLIENT_ASSERTION_TYPE,
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_private_key_jwt(
+        &mock.domain(),
+        client_id,
+        PRIVATE_KEY,
+        "test-key-1",
+        Algorithm::PS256
+    ));
+    assert_ok!(auth.revoke_refresh_token("GEbRxBN...edjnXbL").send().await);
+
+    let requests = assert_some!(mock.received_requests().await);
+    decode_assertion(&requests[0].body, client_id, &format!("{}/", mock.domain()));
+}
+
+#[test]
+fn fail_to_create_with_unsupported_algorithm() {
+    let error = assert_err!(AuthenticationApi::with_private_key_jwt(
+        "https://domain.auth0.com",
+        "xxxyyyzzz",
+        PRIVATE_KEY,
+        "test-key-1",
+        Algorithm::HS256
+    ));
+    assert_matches!(error, Error::Jwt(_));
+}
+
+#[test]
+fn fail_to_create_with_invalid_key() {
+    let error = assert_err!(AuthenticationApi::with_private_key_jwt(
+        "https://domain.auth0.com",
+        "xxxyyyzzz",
+        b"not a key",
+        "test-key-1",
+        Algorithm::RS256
+    ));
+    assert_matches!(error, Error::Jwt(_));
+}
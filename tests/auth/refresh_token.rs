@@ -0,0 +1,85 @@
+use auth0_sdk::{error::ErrorCode, AuthenticationApi};
+use claym::*;
+use serde_json::json;
+use wiremock::matchers;
+
+use crate::mock::*;
+
+#[tokio::test]
+async fn should_refresh_token() {
+    let client_id = "xxxyyyzzz";
+    let refresh_token = "GEbRxBN...edjnXbL";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_json(json!({
+            "grant_type": "refresh_token",
+            "client_id": &client_id,
+            "refresh_token": refresh_token,
+        })))
+        .respond_with(response_auth_tokens())
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let response = assert_ok!(auth.refresh_token(refresh_token).send().await);
+
+    assert!(!response.access_token.is_empty());
+    assert!(!response.token_type.is_empty());
+    assert!(!assert_some!(response.refresh_token).is_empty());
+}
+
+#[tokio::test]
+async fn should_refresh_token_with_client_secret_and_scopes() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+    let refresh_token = "GEbRxBN...edjnXbL";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_json(json!({
+            "grant_type": "refresh_token",
+            "client_id": &client_id,
+            "client_secret": &client_secret,
+            "refresh_token": refresh_token,
+            "scope": "openid profile",
+        })))
+        .respond_with(response_auth_tokens())
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ));
+    let response = assert_ok!(
+        auth.refresh_token(refresh_token)
+            .scopes(["openid", "profile"])
+            .send()
+            .await
+    );
+
+    assert!(!response.access_token.is_empty());
+    assert_some_eq!(response.refresh_token.as_deref(), "GEbRxBN...edjnXbL");
+}
+
+#[tokio::test]
+async fn fail_to_refresh_revoked_token() {
+    let client_id = "xxxyyyzzz";
+    let refresh_token = "GEbRxBN...edjnXbL";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .respond_with(response_auth_error(
+            403,
+            "invalid_grant",
+            "Unknown or invalid refresh token.",
+        ))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(auth.refresh_token(refresh_token).send().await);
+    assert_some_eq!(error.code(), &ErrorCode::InvalidGrant);
+}
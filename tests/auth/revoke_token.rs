@@ -0,0 +1,71 @@
+use auth0_sdk::{error::ErrorCode, AuthenticationApi};
+use claym::*;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
+
+use crate::mock::*;
+
+#[tokio::test]
+async fn should_revoke_refresh_token() {
+    let client_id = "xxxyyyzzz";
+    let refresh_token = "GEbRxBN...edjnXbL";
+
+    let mock = MockApi::new().await;
+    matcher_revoke_token()
+        .and(matchers::body_json(json!({
+            "client_id": &client_id,
+            "token": refresh_token,
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    assert_ok!(auth.revoke_refresh_token(refresh_token).send().await);
+}
+
+#[tokio::test]
+async fn should_revoke_refresh_token_with_client_secret() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+    let refresh_token = "GEbRxBN...edjnXbL";
+
+    let mock = MockApi::new().await;
+    matcher_revoke_token()
+        .and(matchers::body_json(json!({
+            "client_id": &client_id,
+            "client_secret": &client_secret,
+            "token": refresh_token,
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ));
+    assert_ok!(auth.revoke_refresh_token(refresh_token).send().await);
+}
+
+#[tokio::test]
+async fn fail_to_revoke_refresh_token_with_invalid_client() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_revoke_token()
+        .respond_with(response_auth_error(
+            401,
+            "invalid_client",
+            "Client authentication failed",
+        ))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(auth.revoke_refresh_token("GEbRxBN").send().await);
+    assert_some_eq!(error.code(), &ErrorCode::InvalidClient);
+}
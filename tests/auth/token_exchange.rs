@@ -0,0 +1,80 @@
+use auth0_sdk::{error::ErrorCode, AuthenticationApi};
+use claym::*;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
+
+use crate::mock::*;
+
+const SUBJECT_TOKEN_TYPE: &str = "urn:acme:legacy-session-token";
+
+#[tokio::test]
+async fn should_exchange_token() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .and(matchers::body_json(json!({
+            "grant_type": "urn:ietf:params:oauth:grant-type:token-exchange",
+            "client_id": &client_id,
+            "client_secret": &client_secret,
+            "subject_token": "legacy-session-xxx",
+            "subject_token_type": SUBJECT_TOKEN_TYPE,
+            "audience": "https://api.example.com",
+            "scope": "openid profile",
+            "organization": "org_xxxyyyzzz",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": "eyJz93a...k4laUWw",
+            "id_token": "eyJ0XAi...4faeEoQ",
+            "issued_token_type": "urn:ietf:params:oauth:token-type:access_token",
+            "token_type": "Bearer",
+            "expires_in": 86400,
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ));
+    let response = assert_ok!(
+        auth.token_exchange("legacy-session-xxx", SUBJECT_TOKEN_TYPE)
+            .audience("https://api.example.com")
+            .scopes(["openid", "profile"])
+            .organization("org_xxxyyyzzz")
+            .send()
+            .await
+    );
+
+    assert_eq!(response.access_token, "eyJz93a...k4laUWw");
+    assert_some_eq!(
+        response.issued_token_type.as_deref(),
+        "urn:ietf:params:oauth:token-type:access_token"
+    );
+}
+
+#[tokio::test]
+async fn fail_to_exchange_unsupported_token_type() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_token()
+        .respond_with(response_auth_error(
+            400,
+            "unsupported_token_type",
+            "Unsupported subject_token_type: urn:acme:unknown",
+        ))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(
+        auth.token_exchange("legacy-session-xxx", "urn:acme:unknown")
+            .send()
+            .await
+    );
+    assert_some_eq!(error.code(), &ErrorCode::UnsupportedTokenType);
+}
@@ -0,0 +1,72 @@
+use auth0_sdk::{error::ErrorCode, AuthenticationApi};
+use claym::*;
+use http::header;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
+
+use crate::mock::*;
+
+#[tokio::test]
+async fn should_get_user_info() {
+    let client_id = "xxxyyyzzz";
+    let access_token = "A9CvPwFojaBI...";
+
+    let mock = MockApi::new().await;
+    matcher_get_user_info()
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {access_token}"),
+        ))
+        .respond_with(response_auth_user_info())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let user = assert_ok!(auth.user_info(access_token).send().await);
+
+    assert_eq!(user.sub, "auth0|5457edea1b8f22891a000004");
+    assert_some_eq!(user.given_name.as_deref(), "John");
+    assert_some_eq!(user.email_verified, true);
+    assert_some!(user.updated_at);
+    assert_some_eq!(
+        user.values.get("https://example.com/roles"),
+        &json!(["admin"])
+    );
+}
+
+#[tokio::test]
+async fn should_get_user_info_with_numeric_updated_at() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_user_info()
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "sub": "oidc|upstream|xxxyyyzz",
+            "updated_at": 1700000000,
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let user = assert_ok!(auth.user_info("A9CvPwFojaBI...").send().await);
+
+    let updated_at = assert_some!(user.updated_at);
+    assert_eq!(updated_at.unix_timestamp(), 1_700_000_000);
+}
+
+#[tokio::test]
+async fn fail_to_get_user_info_with_invalid_token() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_user_info()
+        .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let error = assert_err!(auth.user_info("invalid").send().await);
+    assert_some_eq!(error.code(), &ErrorCode::Unauthorized);
+}
@@ -0,0 +1,252 @@
+use std::time::Duration;
+
+use auth0_sdk::{AuthenticationApi, Error};
+use claym::*;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde_json::{json, Value as JsonValue};
+use time::OffsetDateTime;
+
+use crate::mock::*;
+
+const PRIVATE_KEY: &[u8] = include_bytes!("../../testdata/auth/private_key.pem");
+
+fn now() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp()
+}
+
+fn sign_rs256(kid: &str, claims: &JsonValue) -> String {
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some(kid.to_string());
+    let key = EncodingKey::from_rsa_pem(PRIVATE_KEY).unwrap();
+    jsonwebtoken::encode(&header, claims, &key).unwrap()
+}
+
+fn sign_hs256(secret: &str, claims: &JsonValue) -> String {
+    let key = EncodingKey::from_secret(secret.as_bytes());
+    jsonwebtoken::encode(&Header::new(Algorithm::HS256), claims, &key).unwrap()
+}
+
+fn id_token_claims(mock: &MockApi, client_id: &str) -> JsonValue {
+    json!({
+        "iss": format!("{}/", mock.domain()),
+        "sub": "auth0|xxxyyyzz",
+        "aud": client_id,
+        "iat": now(),
+        "exp": now() + 3600,
+        "nonce": "~~nonce~~",
+        "auth_time": now() - 30,
+        "name": "John Doe",
+    })
+}
+
+#[tokio::test]
+async fn should_verify_id_token() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_jwks()
+        .respond_with(response_auth_jwks())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let token = sign_rs256("test-key-1", &id_token_claims(&mock, client_id));
+
+    let claims = assert_ok!(
+        auth.verify_id_token(&token)
+            .nonce("~~nonce~~")
+            .max_age(Duration::from_secs(300))
+            .verify()
+            .await
+    );
+    assert_eq!(claims.sub, "auth0|xxxyyyzz");
+    assert_eq!(claims.aud, [client_id]);
+    assert_some_eq!(claims.values.get("name"), &json!("John Doe"));
+
+    // The keys are cached.
+    assert_ok!(auth.verify_id_token(&token).verify().await);
+}
+
+#[tokio::test]
+async fn should_verify_id_token_signed_with_client_secret() {
+    let client_id = "xxxyyyzzz";
+    let client_secret = "secret_of_xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        client_id,
+        client_secret
+    ));
+    let token = sign_hs256(client_secret, &id_token_claims(&mock, client_id));
+
+    assert_ok!(
+        auth.verify_id_token(&token)
+            .algorithms(vec![Algorithm::HS256])
+            .verify()
+            .await
+    );
+
+    // Tokens signed with the client secret are rejected by default.
+    let error = assert_err!(auth.verify_id_token(&token).verify().await);
+    assert_matches!(error, Error::InvalidToken(_));
+}
+
+#[tokio::test]
+async fn fail_to_verify_id_token_with_wrong_nonce() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_jwks()
+        .respond_with(response_auth_jwks())
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let token = sign_rs256("test-key-1", &id_token_claims(&mock, client_id));
+
+    let error = assert_err!(auth.verify_id_token(token).nonce("other").verify().await);
+    assert_matches!(error, Error::InvalidToken(_));
+}
+
+#[tokio::test]
+async fn fail_to_verify_id_token_with_old_authentication() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_jwks()
+        .respond_with(response_auth_jwks())
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let mut claims = id_token_claims(&mock, client_id);
+    claims["auth_time"] = json!(now() - 3600);
+    let token = sign_rs256("test-key-1", &claims);
+
+    let error = assert_err!(
+        auth.verify_id_token(token)
+            .max_age(Duration::from_secs(300))
+            .verify()
+            .await
+    );
+    assert_matches!(error, Error::InvalidToken(_));
+}
+
+#[tokio::test]
+async fn fail_to_verify_id_token_with_overflowing_max_age() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_jwks()
+        .respond_with(response_auth_jwks())
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let token = sign_rs256("test-key-1", &id_token_claims(&mock, client_id));
+
+    let error = assert_err!(
+        auth.verify_id_token(token)
+            .max_age(Duration::from_secs(i64::MAX as u64))
+            .verify()
+            .await
+    );
+    assert_matches!(error, Error::InvalidToken(_));
+}
+
+#[tokio::test]
+async fn fail_to_verify_id_token_for_another_party() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_jwks()
+        .respond_with(response_auth_jwks())
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let mut claims = id_token_claims(&mock, client_id);
+    claims["aud"] = json!([client_id, "another_client"]);
+    claims["azp"] = json!("another_client");
+    let token = sign_rs256("test-key-1", &claims);
+
+    let error = assert_err!(auth.verify_id_token(token).verify().await);
+    assert_matches!(error, Error::InvalidToken(_));
+}
+
+#[tokio::test]
+async fn fail_to_verify_expired_id_token() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_jwks()
+        .respond_with(response_auth_jwks())
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let mut claims = id_token_claims(&mock, client_id);
+    claims["exp"] = json!(now() - 3600);
+    let token = sign_rs256("test-key-1", &claims);
+
+    let error = assert_err!(auth.verify_id_token(token).verify().await);
+    assert_matches!(error, Error::Jwt(_));
+}
+
+#[tokio::test]
+async fn fail_to_verify_id_token_with_unknown_key() {
+    let client_id = "xxxyyyzzz";
+
+    let mock = MockApi::new().await;
+    matcher_get_jwks()
+        .respond_with(response_auth_jwks())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let token = sign_rs256("test-key-2", &id_token_claims(&mock, client_id));
+
+    let error = assert_err!(auth.verify_id_token(&token).verify().await);
+    assert_matches!(error, Error::InvalidToken(_));
+    // The key set is not fetched again right away.
+    assert_err!(auth.verify_id_token(&token).verify().await);
+}
+
+#[tokio::test]
+async fn should_verify_access_token() {
+    let client_id = "xxxyyyzzz";
+    let audience = "https://api.example.com";
+
+    let mock = MockApi::new().await;
+    matcher_get_jwks()
+        .respond_with(response_auth_jwks())
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), client_id));
+    let token = sign_rs256(
+        "test-key-1",
+        &json!({
+            "iss": format!("{}/", mock.domain()),
+            "sub": "auth0|xxxyyyzz",
+            "aud": [audience, format!("{}/userinfo", mock.domain())],
+            "azp": client_id,
+            "exp": now() + 3600,
+            "scope": "openid read:messages",
+            "permissions": ["read:messages"],
+        }),
+    );
+
+    let claims = assert_ok!(auth.verify_access_token(&token, audience).verify().await);
+    assert_some_eq!(claims.scope.as_deref(), "openid read:messages");
+    assert_eq!(claims.permissions, ["read:messages"]);
+
+    assert_err!(
+        auth.verify_access_token(&token, "https://other.example.com")
+            .verify()
+            .await
+    );
+}
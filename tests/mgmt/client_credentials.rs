@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use auth0_sdk::{AuthenticationApi, ManagementApi};
+use claym::*;
+use serde_json::json;
+use wiremock::matchers;
+
+use crate::mock::*;
+
+#[tokio::test]
+async fn fail_to_create_api_with_client_credentials_missing_secret() {
+    let mock = MockApi::new().await;
+    let auth = assert_ok!(AuthenticationApi::new(&mock.domain(), "xxxyyyzzz"));
+    assert_err!(ManagementApi::with_client_credentials(
+        &mock.domain(),
+        auth,
+        mock.audience()
+    ));
+}
+
+#[tokio::test]
+async fn should_request_token_once_for_concurrent_calls() {
+    let mock = MockApi::new().await;
+    matcher_auth_get_token()
+        .and(matchers::body_json(json!({
+            "grant_type": "client_credentials",
+            "client_id": "xxxyyyzzz",
+            "client_secret": "secret_of_xxxyyyzzz",
+            "audience": mock.audience(),
+        })))
+        .respond_with(response_auth_token(&mock, 86400))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(response_mgmt_users_list())
+        .expect(3)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        "xxxyyyzzz",
+        "secret_of_xxxyyyzzz"
+    ));
+    let mgmt = assert_ok!(ManagementApi::with_client_credentials(
+        &mock.domain(),
+        auth,
+        mock.audience()
+    ));
+    let users = mgmt.users();
+
+    let (first, second) = (users.list(), users.list());
+    let (first, second) = tokio::join!(first.send(), second.send());
+    assert_ok!(first);
+    assert_ok!(second);
+    assert_ok!(users.list().send().await);
+}
+
+#[tokio::test(start_paused = true)]
+async fn should_renew_token_before_expiration() {
+    let mock = MockApi::new().await;
+    matcher_auth_get_token()
+        .respond_with(response_auth_token(&mock, 3600))
+        .expect(2)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(response_mgmt_users_list())
+        .expect(3)
+        .mount(&mock)
+        .await;
+
+    let auth = assert_ok!(AuthenticationApi::with_client_secret(
+        &mock.domain(),
+        "xxxyyyzzz",
+        "secret_of_xxxyyyzzz"
+    ));
+    let mgmt = assert_ok!(ManagementApi::with_client_credentials(
+        &mock.domain(),
+        auth,
+        mock.audience()
+    ));
+    let users = mgmt.users();
+
+    assert_ok!(users.list().send().await);
+    tokio::time::advance(Duration::from_secs(3000)).await;
+    assert_ok!(users.list().send().await);
+    tokio::time::advance(Duration::from_secs(580)).await;
+    assert_ok!(users.list().send().await);
+}
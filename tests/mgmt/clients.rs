@@ -1,6 +1,8 @@
-use auth0_sdk::ManagementApi;
+use auth0_sdk::{models::ClientSettings, ManagementApi};
 use claym::*;
-use wiremock::matchers;
+use futures::TryStreamExt;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
 
 use crate::mock::*;
 
@@ -188,3 +190,132 @@ async fn should_get_client_with_fields() {
             .await
     );
 }
+
+#[tokio::test]
+async fn should_stream_clients() {
+    let mock = MockApi::new().await;
+    let clients = mgmt_clients();
+    matcher_mgmt_clients_list(&mock)
+        .and(matchers::query_param("page", "0"))
+        .and(matchers::query_param("per_page", "2"))
+        .and(matchers::query_param("include_totals", "true"))
+        .respond_with(response_mgmt_paged("clients", &clients, 0, 4))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_clients_list(&mock)
+        .and(matchers::query_param("page", "1"))
+        .and(matchers::query_param("per_page", "2"))
+        .and(matchers::query_param("include_totals", "true"))
+        .respond_with(response_mgmt_paged("clients", &clients, 2, 4))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let clients = mgmt.clients();
+
+    let stream = clients.list().per_page(2).into_stream();
+    let clients: Vec<_> = assert_ok!(stream.try_collect().await);
+    assert_eq!(clients.len(), 4);
+}
+
+#[tokio::test]
+async fn should_create_client() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_clients_create(&mock)
+        .and(matchers::body_json(json!({
+            "name": "My-Super-Application-Name",
+            "app_type": "regular_web",
+            "callbacks": ["https://example.com/callback"],
+        })))
+        .respond_with(response_mgmt_client())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let clients = mgmt.clients();
+
+    let settings = ClientSettings {
+        name: Some("My-Super-Application-Name".into()),
+        app_type: Some("regular_web".into()),
+        callbacks: Some(vec!["https://example.com/callback".into()]),
+        ..Default::default()
+    };
+    let client = assert_ok!(clients.create(settings).send().await);
+    assert_some!(client.client_id);
+}
+
+#[tokio::test]
+async fn should_update_client() {
+    let mock = MockApi::new().await;
+    let client_id = "My-Super-Application-Name";
+    matcher_mgmt_clients_update(&mock, client_id)
+        .and(matchers::body_json(json!({
+            "allowed_logout_urls": [],
+            "grant_types": ["authorization_code", "refresh_token"],
+        })))
+        .respond_with(response_mgmt_client())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let clients = mgmt.clients();
+
+    let settings = ClientSettings {
+        allowed_logout_urls: Some(vec![]),
+        grant_types: Some(vec!["authorization_code".into(), "refresh_token".into()]),
+        ..Default::default()
+    };
+    assert_ok!(clients.update(client_id, settings).send().await);
+}
+
+#[tokio::test]
+async fn should_delete_client() {
+    let mock = MockApi::new().await;
+    let client_id = "My-Super-Application-Name";
+    matcher_mgmt_clients_delete(&mock, client_id)
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let clients = mgmt.clients();
+
+    assert_ok!(clients.delete(client_id).send().await);
+}
+
+#[tokio::test]
+async fn should_encode_client_id() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_clients_rotate_secret(&mock, "My%2F..%2FApplication%3FName")
+        .respond_with(response_mgmt_client())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let clients = mgmt.clients();
+
+    assert_ok!(clients.rotate_secret("My/../Application?Name").send().await);
+}
+
+#[tokio::test]
+async fn should_rotate_client_secret() {
+    let mock = MockApi::new().await;
+    let client_id = "My-Super-Application-Name";
+    matcher_mgmt_clients_rotate_secret(&mock, client_id)
+        .respond_with(response_mgmt_client())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let clients = mgmt.clients();
+
+    let client = assert_ok!(clients.rotate_secret(client_id).send().await);
+    assert_some!(client.client_secret);
+}
@@ -0,0 +1,255 @@
+use auth0_sdk::{
+    error::ErrorCode,
+    models::{ConnectionOptions, DatabaseConnectionOptions},
+    ManagementApi,
+};
+use claym::*;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
+
+use crate::mock::*;
+
+#[tokio::test]
+async fn should_list_connections_with_typed_options() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_connections_list(&mock)
+        .respond_with(response_mgmt_connections_list())
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    let response = assert_ok!(connections.list().send().await);
+    let options: Vec<_> = response.connections.iter().map(|c| &c.options).collect();
+    assert_matches!(options[0], ConnectionOptions::Database(_));
+    assert_matches!(options[1], ConnectionOptions::GoogleOAuth2(google) if google.scope.as_ref().unwrap().len() == 2);
+    assert_matches!(options[2], ConnectionOptions::Saml(saml) if saml.sign_saml_request == Some(true));
+    assert_matches!(options[3], ConnectionOptions::Other(raw) if raw["read_user"] == true);
+}
+
+#[tokio::test]
+async fn should_list_connections_by_strategy_and_name() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_connections_list(&mock)
+        .and(matchers::query_param("strategy", "auth0"))
+        .and(matchers::query_param(
+            "name",
+            "Username-Password-Authentication",
+        ))
+        .respond_with(response_mgmt_paged("connections", &[], 0, 0))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    let response = assert_ok!(
+        connections
+            .list()
+            .strategy("auth0")
+            .name("Username-Password-Authentication")
+            .include_totals(true)
+            .send()
+            .await
+    );
+    assert!(response.connections.is_empty());
+    assert_some_eq!(response.total, 0);
+}
+
+#[tokio::test]
+async fn should_get_connection() {
+    let mock = MockApi::new().await;
+    let connection_id = "con_0ABCDEF1234567890";
+    matcher_mgmt_connections_get(&mock, connection_id)
+        .respond_with(response_mgmt_connection())
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    let connection = assert_ok!(connections.get(connection_id).send().await);
+    assert_eq!(connection.strategy, "auth0");
+    let ConnectionOptions::Database(options) = connection.options else {
+        panic!("unexpected options {:?}", connection.options);
+    };
+    assert_some_eq!(options.password_policy, "good");
+    assert_some_eq!(options.brute_force_protection, true);
+    assert_eq!(options.values["mfa"]["active"], true);
+}
+
+#[tokio::test]
+async fn should_fallback_to_raw_options_when_invalid() {
+    let mock = MockApi::new().await;
+    let connection_id = "con_0ABCDEF1234567890";
+    matcher_mgmt_connections_get(&mock, connection_id)
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": connection_id,
+            "name": "Username-Password-Authentication",
+            "strategy": "auth0",
+            "options": { "passwordPolicy": 3 },
+        })))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    let connection = assert_ok!(connections.get(connection_id).send().await);
+    assert_matches!(connection.options, ConnectionOptions::Other(raw) if raw["passwordPolicy"] == 3);
+}
+
+#[tokio::test]
+async fn should_create_connection() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_connections_create(&mock)
+        .and(matchers::body_json(json!({
+            "name": "Username-Password-Authentication",
+            "strategy": "auth0",
+            "options": {
+                "passwordPolicy": "good",
+                "brute_force_protection": true,
+            },
+            "enabled_clients": ["0ABCDEF1234567890abcdef"],
+            "realms": ["users", "employees", "contractors"],
+        })))
+        .respond_with(response_mgmt_connection())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    assert_ok!(
+        connections
+            .create("Username-Password-Authentication", "auth0")
+            .options(DatabaseConnectionOptions {
+                password_policy: Some("good".to_string()),
+                brute_force_protection: Some(true),
+                ..Default::default()
+            })
+            .enabled_client("0ABCDEF1234567890abcdef")
+            .realm("users")
+            .realms(["employees", "contractors"])
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_update_connection() {
+    let mock = MockApi::new().await;
+    let connection_id = "con_0ABCDEF1234567890";
+    matcher_mgmt_connections_update(&mock, connection_id)
+        .and(matchers::body_json(json!({
+            "display_name": "Acme Users",
+            "enabled_clients": [],
+        })))
+        .respond_with(response_mgmt_connection())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    assert_ok!(
+        connections
+            .update(connection_id)
+            .display_name("Acme Users")
+            .enabled_clients(Vec::<String>::new())
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_delete_connection() {
+    let mock = MockApi::new().await;
+    let connection_id = "con_0ABCDEF1234567890";
+    matcher_mgmt_connections_delete(&mock, connection_id)
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    assert_ok!(connections.delete(connection_id).send().await);
+}
+
+#[tokio::test]
+async fn should_encode_connection_id() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_connections_delete(&mock, "con_..%2F..%2Fusers%3Fq")
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    assert_ok!(connections.delete("con_../../users?q").send().await);
+}
+
+#[tokio::test]
+async fn should_check_connection_status() {
+    let mock = MockApi::new().await;
+    let connection_id = "con_2CDEFGH3456789012";
+    matcher_mgmt_connections_status(&mock, connection_id)
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    assert_ok!(connections.status(connection_id).send().await);
+}
+
+#[tokio::test]
+async fn fail_to_check_offline_connection_status() {
+    let mock = MockApi::new().await;
+    let connection_id = "con_2CDEFGH3456789012";
+    matcher_mgmt_connections_status(&mock, connection_id)
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "statusCode": 404,
+            "error": "Not Found",
+            "message": "Connection is offline",
+        })))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    let error = assert_err!(connections.status(connection_id).send().await);
+    assert_some_eq!(error.code(), &ErrorCode::NotFound);
+}
+
+#[tokio::test]
+async fn should_delete_connection_user_by_email() {
+    let mock = MockApi::new().await;
+    let connection_id = "con_0ABCDEF1234567890";
+    matcher_mgmt_connections_delete_user(&mock, connection_id)
+        .and(matchers::query_param("email", "john.doe@gmail.com"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let connections = mgmt.connections();
+
+    assert_ok!(
+        connections
+            .delete_user(connection_id, "john.doe@gmail.com")
+            .send()
+            .await
+    );
+}
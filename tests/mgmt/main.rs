@@ -1,3 +1,8 @@
+mod client_credentials;
 mod clients;
+mod connections;
 mod mock;
+mod organizations;
+mod retry;
+mod roles;
 mod users;
@@ -2,6 +2,7 @@ use std::ops::Deref;
 
 use fake::Fake;
 use http::header;
+use serde_json::{json, Value as JsonValue};
 use wiremock::{matchers, Mock, MockBuilder, MockServer, ResponseTemplate};
 
 pub struct MockApi {
@@ -22,6 +23,11 @@ impl MockApi {
         &self.api_token
     }
 
+    /// Get the audience of the management api.
+    pub fn audience(&self) -> String {
+        format!("{}/api/v2/", self.server.uri())
+    }
+
     /// Get the api address.
     pub fn domain(&self) -> String {
         self.server.uri()
@@ -38,6 +44,14 @@ impl Deref for MockApi {
 
 // responses
 // ----------------------------------------------------------------------------
+pub fn response_auth_token(api: &MockApi, expires_in: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "access_token": api.api_token(),
+        "token_type": "Bearer",
+        "expires_in": expires_in,
+    }))
+}
+
 pub fn response_mgmt_client() -> ResponseTemplate {
     const BODY: &[u8] = include_bytes!("../../testdata/mgmt/client.json");
     json_response_template(BODY)
@@ -68,8 +82,110 @@ pub fn response_mgmt_users_paged_list() -> ResponseTemplate {
     json_response_template(BODY)
 }
 
+pub fn response_mgmt_role() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/role.json");
+    json_response_template(BODY)
+}
+
+pub fn response_mgmt_roles_list() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/roles_list.json");
+    json_response_template(BODY)
+}
+
+pub fn response_mgmt_role_permissions_list() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/role_permissions_list.json");
+    json_response_template(BODY)
+}
+
+pub fn response_mgmt_user_permissions_list() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/user_permissions_list.json");
+    json_response_template(BODY)
+}
+
+pub fn response_mgmt_organization() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/organization.json");
+    json_response_template(BODY)
+}
+
+pub fn response_mgmt_organizations_list() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/organizations_list.json");
+    json_response_template(BODY)
+}
+
+pub fn response_mgmt_organization_connections_list() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/organization_connections_list.json");
+    json_response_template(BODY)
+}
+
+pub fn response_mgmt_organization_invitation() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/organization_invitation.json");
+    json_response_template(BODY)
+}
+
+pub fn response_mgmt_connection() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/connection.json");
+    json_response_template(BODY)
+}
+
+pub fn response_mgmt_connections_list() -> ResponseTemplate {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/connections_list.json");
+    json_response_template(BODY)
+}
+
+pub fn response_mgmt_error(status: u16, error_code: &str, message: &str) -> ResponseTemplate {
+    let reason = http::StatusCode::from_u16(status)
+        .ok()
+        .and_then(|status| status.canonical_reason())
+        .unwrap_or_default();
+    ResponseTemplate::new(status).set_body_json(json!({
+        "statusCode": status,
+        "error": reason,
+        "message": message,
+        "errorCode": error_code,
+    }))
+}
+
+pub fn response_mgmt_paged(
+    key: &str,
+    items: &[JsonValue],
+    start: usize,
+    total: usize,
+) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "start": start,
+        "limit": items.len(),
+        "length": items.len(),
+        "total": total,
+        key: items,
+    }))
+}
+
+pub fn mgmt_users() -> Vec<JsonValue> {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/users_list.json");
+    serde_json::from_slice(BODY).unwrap()
+}
+
+pub fn mgmt_clients() -> Vec<JsonValue> {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/clients_list.json");
+    serde_json::from_slice(BODY).unwrap()
+}
+
+pub fn mgmt_roles() -> Vec<JsonValue> {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/roles_list.json");
+    serde_json::from_slice(BODY).unwrap()
+}
+
+pub fn mgmt_organization_members() -> Vec<JsonValue> {
+    const BODY: &[u8] = include_bytes!("../../testdata/mgmt/organization_members.json");
+    serde_json::from_slice(BODY).unwrap()
+}
+
 // matchers
 // ----------------------------------------------------------------------------
+pub fn matcher_auth_get_token() -> MockBuilder {
+    Mock::given(matchers::method("POST")).and(matchers::path("/oauth/token"))
+}
+
 pub fn matcher_mgmt_clients_list(api: &MockApi) -> MockBuilder {
     Mock::given(matchers::method("GET"))
         .and(matchers::path("/api/v2/clients"))
@@ -88,6 +204,44 @@ pub fn matcher_mgmt_clients_get(api: &MockApi, id: &str) -> MockBuilder {
         ))
 }
 
+pub fn matcher_mgmt_clients_create(api: &MockApi) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path("/api/v2/clients"))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_clients_update(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("PATCH"))
+        .and(matchers::path(format!("/api/v2/clients/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_clients_delete(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!("/api/v2/clients/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_clients_rotate_secret(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path(format!(
+            "/api/v2/clients/{id}/rotate-secret"
+        )))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
 pub fn matcher_mgmt_users_list(api: &MockApi) -> MockBuilder {
     Mock::given(matchers::method("GET"))
         .and(matchers::path("/api/v2/users"))
@@ -106,6 +260,401 @@ pub fn matcher_mgmt_users_get(api: &MockApi, id: &str) -> MockBuilder {
         ))
 }
 
+pub fn matcher_mgmt_users_create(api: &MockApi) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path("/api/v2/users"))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_users_update(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("PATCH"))
+        .and(matchers::path(format!("/api/v2/users/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_users_delete(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!("/api/v2/users/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_users_roles(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!("/api/v2/users/{id}/roles")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_users_assign_roles(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path(format!("/api/v2/users/{id}/roles")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_users_remove_roles(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!("/api/v2/users/{id}/roles")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_users_permissions(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!("/api/v2/users/{id}/permissions")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_users_assign_permissions(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path(format!("/api/v2/users/{id}/permissions")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_users_remove_permissions(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!("/api/v2/users/{id}/permissions")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_roles_list(api: &MockApi) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path("/api/v2/roles"))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_roles_get(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!("/api/v2/roles/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_roles_create(api: &MockApi) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path("/api/v2/roles"))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_roles_update(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("PATCH"))
+        .and(matchers::path(format!("/api/v2/roles/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_roles_delete(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!("/api/v2/roles/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_roles_permissions(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!("/api/v2/roles/{id}/permissions")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_roles_add_permissions(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path(format!("/api/v2/roles/{id}/permissions")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_roles_remove_permissions(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!("/api/v2/roles/{id}/permissions")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_roles_users(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!("/api/v2/roles/{id}/users")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_roles_assign_users(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path(format!("/api/v2/roles/{id}/users")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_connections_list(api: &MockApi) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path("/api/v2/connections"))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_connections_get(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!("/api/v2/connections/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_connections_create(api: &MockApi) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path("/api/v2/connections"))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_connections_update(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("PATCH"))
+        .and(matchers::path(format!("/api/v2/connections/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_connections_delete(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!("/api/v2/connections/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_connections_status(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!("/api/v2/connections/{id}/status")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_connections_delete_user(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!("/api/v2/connections/{id}/users")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_list(api: &MockApi) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path("/api/v2/organizations"))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_get(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!("/api/v2/organizations/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_get_by_name(api: &MockApi, name: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!("/api/v2/organizations/name/{name}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_create(api: &MockApi) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path("/api/v2/organizations"))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_update(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("PATCH"))
+        .and(matchers::path(format!("/api/v2/organizations/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_delete(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!("/api/v2/organizations/{id}")))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_members(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!(
+            "/api/v2/organizations/{id}/members"
+        )))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_add_members(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path(format!(
+            "/api/v2/organizations/{id}/members"
+        )))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_remove_members(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!(
+            "/api/v2/organizations/{id}/members"
+        )))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_assign_member_roles(
+    api: &MockApi,
+    id: &str,
+    user_id: &str,
+) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path(format!(
+            "/api/v2/organizations/{id}/members/{user_id}/roles"
+        )))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_connections(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!(
+            "/api/v2/organizations/{id}/enabled_connections"
+        )))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_add_connection(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path(format!(
+            "/api/v2/organizations/{id}/enabled_connections"
+        )))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_invitations(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("GET"))
+        .and(matchers::path(format!(
+            "/api/v2/organizations/{id}/invitations"
+        )))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_create_invitation(api: &MockApi, id: &str) -> MockBuilder {
+    Mock::given(matchers::method("POST"))
+        .and(matchers::path(format!(
+            "/api/v2/organizations/{id}/invitations"
+        )))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
+pub fn matcher_mgmt_organizations_delete_invitation(
+    api: &MockApi,
+    id: &str,
+    invitation_id: &str,
+) -> MockBuilder {
+    Mock::given(matchers::method("DELETE"))
+        .and(matchers::path(format!(
+            "/api/v2/organizations/{id}/invitations/{invitation_id}"
+        )))
+        .and(matchers::header(
+            header::AUTHORIZATION,
+            format!("Bearer {}", api.api_token()),
+        ))
+}
+
 fn json_response_template(data: &[u8]) -> ResponseTemplate {
     ResponseTemplate::new(200).set_body_raw(data, "application/json")
 }
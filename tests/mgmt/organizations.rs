@@ -0,0 +1,459 @@
+use auth0_sdk::{
+    error::ErrorCode,
+    models::{OrganizationBranding, OrganizationBrandingColors},
+    ManagementApi,
+};
+use claym::*;
+use futures::TryStreamExt;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
+
+use crate::mock::*;
+
+#[tokio::test]
+async fn should_list_organizations() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_organizations_list(&mock)
+        .and(matchers::query_param("per_page", "10"))
+        .respond_with(response_mgmt_organizations_list())
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    let response = assert_ok!(organizations.list().per_page(10).send().await);
+    assert_eq!(response.organizations.len(), 2);
+    assert_eq!(response.organizations[1].name, "globex");
+}
+
+#[tokio::test]
+async fn should_get_organization() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    matcher_mgmt_organizations_get(&mock, organization_id)
+        .respond_with(response_mgmt_organization())
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    let organization = assert_ok!(organizations.get(organization_id).send().await);
+    assert_eq!(organization.id, organization_id);
+    let branding = assert_some!(organization.branding);
+    assert_eq!(assert_some!(branding.colors).primary, "#0059d6");
+    assert_eq!(organization.metadata["plan"], "enterprise");
+}
+
+#[tokio::test]
+async fn should_get_organization_by_name() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_organizations_get_by_name(&mock, "acme")
+        .respond_with(response_mgmt_organization())
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    let organization = assert_ok!(organizations.get_by_name("acme").send().await);
+    assert_some_eq!(organization.display_name, "Acme Corporation");
+}
+
+#[tokio::test]
+async fn should_encode_organization_name() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_organizations_get_by_name(&mock, "acme%2F..%2Fusers%3Fq")
+        .respond_with(response_mgmt_organization())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    assert_ok!(organizations.get_by_name("acme/../users?q").send().await);
+}
+
+#[tokio::test]
+async fn fail_to_get_unknown_organization() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_unknown";
+    matcher_mgmt_organizations_get(&mock, organization_id)
+        .respond_with(response_mgmt_error(
+            404,
+            "inexistent_organization",
+            "No organization found by that id or name",
+        ))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    let error = assert_err!(organizations.get(organization_id).send().await);
+    assert_some_eq!(
+        error.code(),
+        &ErrorCode::Other("inexistent_organization".to_string())
+    );
+}
+
+#[tokio::test]
+async fn should_create_organization() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_organizations_create(&mock)
+        .and(matchers::body_json(json!({
+            "name": "acme",
+            "display_name": "Acme Corporation",
+            "branding": {
+                "logo_url": "https://acme.example.com/logo.png",
+                "colors": {
+                    "primary": "#0059d6",
+                    "page_background": "#000000",
+                },
+            },
+            "metadata": { "plan": "enterprise" },
+        })))
+        .respond_with(response_mgmt_organization())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    assert_ok!(
+        organizations
+            .create("acme")
+            .display_name("Acme Corporation")
+            .branding(OrganizationBranding {
+                logo_url: Some("https://acme.example.com/logo.png".to_string()),
+                colors: Some(OrganizationBrandingColors {
+                    primary: "#0059d6".to_string(),
+                    page_background: "#000000".to_string(),
+                }),
+            })
+            .metadata("plan", "enterprise")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_update_organization() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    matcher_mgmt_organizations_update(&mock, organization_id)
+        .and(matchers::body_json(json!({
+            "display_name": "Acme Corp.",
+            "metadata": { "plan": "business", "trial": null },
+        })))
+        .respond_with(response_mgmt_organization())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    assert_ok!(
+        organizations
+            .update(organization_id)
+            .display_name("Acme Corp.")
+            .metadata("plan", "business")
+            .remove_metadata("trial")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_delete_organization() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    matcher_mgmt_organizations_delete(&mock, organization_id)
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    assert_ok!(organizations.delete(organization_id).send().await);
+}
+
+#[tokio::test]
+async fn should_list_organization_members_with_roles() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    let members = mgmt_organization_members();
+    matcher_mgmt_organizations_members(&mock, organization_id)
+        .and(matchers::query_param("take", "50"))
+        .and(matchers::query_param("fields", "user_id,roles"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "members": members })))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    let response = assert_ok!(
+        organizations
+            .members(organization_id)
+            .fields(["user_id", "roles"])
+            .send()
+            .await
+    );
+    assert_eq!(response.members.len(), 2);
+    assert_eq!(response.members[0].roles[0].name, "Administrator");
+    assert_none!(response.next);
+}
+
+#[tokio::test]
+async fn should_stream_organization_members() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    let members = mgmt_organization_members();
+    matcher_mgmt_organizations_members(&mock, organization_id)
+        .and(matchers::query_param("take", "1"))
+        .and(matchers::query_param_is_missing("from"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "members": &members[..1],
+            "next": "checkpoint-1",
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_organizations_members(&mock, organization_id)
+        .and(matchers::query_param("take", "1"))
+        .and(matchers::query_param("from", "checkpoint-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "members": &members[1..],
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    let members: Vec<_> = assert_ok!(
+        organizations
+            .members(organization_id)
+            .take(1)
+            .into_stream()
+            .try_collect()
+            .await
+    );
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[1].user_id, "auth0|zzyyyxxx");
+}
+
+#[tokio::test]
+async fn should_add_organization_members() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    matcher_mgmt_organizations_add_members(&mock, organization_id)
+        .and(matchers::body_json(json!({
+            "members": ["auth0|xxxyyyzz", "auth0|zzyyyxxx"],
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    assert_ok!(
+        organizations
+            .add_members(organization_id)
+            .members(["auth0|xxxyyyzz", "auth0|zzyyyxxx"])
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_remove_organization_members() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    matcher_mgmt_organizations_remove_members(&mock, organization_id)
+        .and(matchers::body_json(json!({
+            "members": ["auth0|zzyyyxxx"],
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    assert_ok!(
+        organizations
+            .remove_members(organization_id)
+            .member("auth0|zzyyyxxx")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_assign_organization_member_roles() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    let user_id = "auth0|xxxyyyzz";
+    matcher_mgmt_organizations_assign_member_roles(&mock, organization_id, "auth0%7Cxxxyyyzz")
+        .and(matchers::body_json(json!({
+            "roles": ["rol_0ABCDEF1234567890"],
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    assert_ok!(
+        organizations
+            .assign_member_roles(organization_id, user_id)
+            .role("rol_0ABCDEF1234567890")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_list_organization_connections() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    matcher_mgmt_organizations_connections(&mock, organization_id)
+        .respond_with(response_mgmt_organization_connections_list())
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    let response = assert_ok!(organizations.connections(organization_id).send().await);
+    assert_eq!(response.enabled_connections.len(), 1);
+    let connection = &response.enabled_connections[0];
+    assert!(connection.assign_membership_on_login);
+    assert_eq!(assert_some!(&connection.connection).strategy, "auth0");
+}
+
+#[tokio::test]
+async fn should_add_organization_connection() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    matcher_mgmt_organizations_add_connection(&mock, organization_id)
+        .and(matchers::body_json(json!({
+            "connection_id": "con_0ABCDEF1234567890",
+            "assign_membership_on_login": true,
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "connection_id": "con_0ABCDEF1234567890",
+            "assign_membership_on_login": true,
+        })))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    let connection = assert_ok!(
+        organizations
+            .add_connection(organization_id, "con_0ABCDEF1234567890")
+            .assign_membership_on_login(true)
+            .send()
+            .await
+    );
+    assert!(connection.assign_membership_on_login);
+}
+
+#[tokio::test]
+async fn should_create_organization_invitation() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    matcher_mgmt_organizations_create_invitation(&mock, organization_id)
+        .and(matchers::body_json(json!({
+            "inviter": { "name": "John Doe" },
+            "invitee": { "email": "jane.doe@gmail.com" },
+            "client_id": "0ABCDEF1234567890abcdef",
+            "connection_id": "con_0ABCDEF1234567890",
+            "roles": ["rol_0ABCDEF1234567890"],
+        })))
+        .respond_with(response_mgmt_organization_invitation())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    let invitation = assert_ok!(
+        organizations
+            .create_invitation(
+                organization_id,
+                "John Doe",
+                "jane.doe@gmail.com",
+                "0ABCDEF1234567890abcdef",
+            )
+            .connection_id("con_0ABCDEF1234567890")
+            .role("rol_0ABCDEF1234567890")
+            .send()
+            .await
+    );
+    assert_eq!(invitation.invitee.email, "jane.doe@gmail.com");
+    assert_some!(invitation.expires_at);
+}
+
+#[tokio::test]
+async fn should_list_organization_invitations() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    matcher_mgmt_organizations_invitations(&mock, organization_id)
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            format!(
+                "[{}]",
+                include_str!("../../testdata/mgmt/organization_invitation.json")
+            ),
+            "application/json",
+        ))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    let response = assert_ok!(organizations.invitations(organization_id).send().await);
+    assert_eq!(response.invitations.len(), 1);
+    assert_eq!(response.invitations[0].inviter.name, "John Doe");
+}
+
+#[tokio::test]
+async fn should_delete_organization_invitation() {
+    let mock = MockApi::new().await;
+    let organization_id = "org_0ABCDEF1234567890";
+    let invitation_id = "uinv_0ABCDEF1234567890";
+    matcher_mgmt_organizations_delete_invitation(&mock, organization_id, invitation_id)
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let organizations = mgmt.organizations();
+
+    assert_ok!(
+        organizations
+            .delete_invitation(organization_id, invitation_id)
+            .send()
+            .await
+    );
+}
@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use auth0_sdk::{error::ErrorCode, retry::RetryPolicy, ManagementApi};
+use claym::*;
+use time::OffsetDateTime;
+use wiremock::ResponseTemplate;
+
+use crate::mock::*;
+
+#[tokio::test(start_paused = true)]
+async fn should_retry_after_rate_limit() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(
+            response_mgmt_error(429, "too_many_requests", "Global limit has been reached")
+                .insert_header("retry-after", "2")
+                .insert_header("x-ratelimit-limit", "50")
+                .insert_header("x-ratelimit-remaining", "0")
+                .insert_header("x-ratelimit-reset", "1700000000"),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(
+            response_mgmt_users_list()
+                .insert_header("x-ratelimit-limit", "50")
+                .insert_header("x-ratelimit-remaining", "49")
+                .insert_header("x-ratelimit-reset", "1700000001"),
+        )
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    assert_none!(mgmt.rate_limit());
+
+    let started_at = tokio::time::Instant::now();
+    let response = assert_ok!(mgmt.users().list().send().await);
+    assert_eq!(response.users.len(), 2);
+    assert!(started_at.elapsed() >= Duration::from_secs(2));
+
+    let rate_limit = assert_some!(mgmt.rate_limit());
+    assert_some_eq!(rate_limit.limit, 50);
+    assert_some_eq!(rate_limit.remaining, 49);
+    assert_eq!(
+        assert_some!(rate_limit.reset).unix_timestamp(),
+        1_700_000_001
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn should_retry_on_server_error() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(response_mgmt_users_list())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    assert_ok!(mgmt.users().list().send().await);
+}
+
+#[tokio::test]
+async fn should_retry_on_server_error_with_backoff() {
+    let reset = OffsetDateTime::now_utc().unix_timestamp() + 3600;
+    let mock = MockApi::new().await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(ResponseTemplate::new(503).insert_header("x-ratelimit-reset", reset))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(response_mgmt_users_list())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token())).with_retry_policy(
+        RetryPolicy {
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_secs(60),
+            ..Default::default()
+        },
+    );
+    // The rate limit reset is ignored, the request is retried after the backoff delay.
+    let response =
+        assert_ok!(tokio::time::timeout(Duration::from_secs(5), mgmt.users().list().send()).await);
+    assert_ok!(response);
+}
+
+#[tokio::test(start_paused = true)]
+async fn should_not_retry_rate_limited_post() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_users_create(&mock)
+        .respond_with(response_mgmt_error(
+            429,
+            "too_many_requests",
+            "Global limit has been reached",
+        ))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let error = assert_err!(
+        mgmt.users()
+            .create("Username-Password-Authentication")
+            .email("john.doe@gmail.com")
+            .send()
+            .await
+    );
+    assert_some_eq!(error.code(), &ErrorCode::TooManyRequests);
+}
+
+#[tokio::test]
+async fn should_not_retry_when_disabled() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()))
+        .with_retry_policy(RetryPolicy::none());
+    assert_err!(mgmt.users().list().send().await);
+}
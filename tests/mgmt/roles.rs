@@ -0,0 +1,307 @@
+use auth0_sdk::{error::ErrorCode, ManagementApi};
+use claym::*;
+use futures::TryStreamExt;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
+
+use crate::mock::*;
+
+#[tokio::test]
+async fn should_list_roles() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_roles_list(&mock)
+        .and(matchers::query_param("name_filter", "admin"))
+        .respond_with(response_mgmt_roles_list())
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    let response = assert_ok!(roles.list().name_filter("admin").send().await);
+    assert_eq!(response.roles.len(), 2);
+    assert_eq!(response.roles[0].name, "Administrator");
+    assert_none!(response.total);
+}
+
+#[tokio::test]
+async fn should_stream_roles() {
+    let mock = MockApi::new().await;
+    let roles = mgmt_roles();
+    matcher_mgmt_roles_list(&mock)
+        .and(matchers::query_param("page", "0"))
+        .and(matchers::query_param("per_page", "2"))
+        .and(matchers::query_param("include_totals", "true"))
+        .respond_with(response_mgmt_paged("roles", &roles, 0, 3))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_roles_list(&mock)
+        .and(matchers::query_param("page", "1"))
+        .and(matchers::query_param("per_page", "2"))
+        .and(matchers::query_param("include_totals", "true"))
+        .respond_with(response_mgmt_paged("roles", &roles[..1], 2, 3))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    let roles: Vec<_> = assert_ok!(roles.list().per_page(2).into_stream().try_collect().await);
+    assert_eq!(roles.len(), 3);
+}
+
+#[tokio::test]
+async fn should_get_role() {
+    let mock = MockApi::new().await;
+    let role_id = "rol_0ABCDEF1234567890";
+    matcher_mgmt_roles_get(&mock, role_id)
+        .respond_with(response_mgmt_role())
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    let role = assert_ok!(roles.get(role_id).send().await);
+    assert_eq!(role.id, role_id);
+    assert_some_eq!(role.description, "Full access to the tenant");
+}
+
+#[tokio::test]
+async fn should_encode_role_id() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_roles_assign_users(&mock, "rol_..%2F..%2Fusers%7Cq")
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    assert_ok!(
+        roles
+            .assign_users("rol_../../users|q")
+            .user("auth0|xxxyyyzz")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_create_role() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_roles_create(&mock)
+        .and(matchers::body_json(json!({
+            "name": "Administrator",
+            "description": "Full access to the tenant",
+        })))
+        .respond_with(response_mgmt_role())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    assert_ok!(
+        roles
+            .create("Administrator")
+            .description("Full access to the tenant")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_update_role() {
+    let mock = MockApi::new().await;
+    let role_id = "rol_0ABCDEF1234567890";
+    matcher_mgmt_roles_update(&mock, role_id)
+        .and(matchers::body_json(json!({
+            "description": "Full access to the tenant",
+        })))
+        .respond_with(response_mgmt_role())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    assert_ok!(
+        roles
+            .update(role_id)
+            .description("Full access to the tenant")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_delete_role() {
+    let mock = MockApi::new().await;
+    let role_id = "rol_0ABCDEF1234567890";
+    matcher_mgmt_roles_delete(&mock, role_id)
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    assert_ok!(roles.delete(role_id).send().await);
+}
+
+#[tokio::test]
+async fn fail_to_get_unknown_role() {
+    let mock = MockApi::new().await;
+    let role_id = "rol_unknown";
+    matcher_mgmt_roles_get(&mock, role_id)
+        .respond_with(response_mgmt_error(
+            404,
+            "inexistent_role",
+            "The role does not exist.",
+        ))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    let error = assert_err!(roles.get(role_id).send().await);
+    assert_some_eq!(
+        error.code(),
+        &ErrorCode::Other("inexistent_role".to_string())
+    );
+}
+
+#[tokio::test]
+async fn should_list_role_permissions() {
+    let mock = MockApi::new().await;
+    let role_id = "rol_0ABCDEF1234567890";
+    matcher_mgmt_roles_permissions(&mock, role_id)
+        .respond_with(response_mgmt_role_permissions_list())
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    let response = assert_ok!(roles.permissions(role_id).send().await);
+    assert_eq!(response.permissions.len(), 2);
+    assert_eq!(response.permissions[0].permission_name, "read:messages");
+    assert_some_eq!(&response.permissions[0].resource_server_name, "Example API");
+}
+
+#[tokio::test]
+async fn should_add_role_permissions() {
+    let mock = MockApi::new().await;
+    let role_id = "rol_0ABCDEF1234567890";
+    matcher_mgmt_roles_add_permissions(&mock, role_id)
+        .and(matchers::body_json(json!({
+            "permissions": [
+                {
+                    "resource_server_identifier": "https://api.example.com",
+                    "permission_name": "read:messages",
+                },
+                {
+                    "resource_server_identifier": "https://api.example.com",
+                    "permission_name": "write:messages",
+                },
+            ],
+        })))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    assert_ok!(
+        roles
+            .add_permissions(role_id)
+            .permission("https://api.example.com", "read:messages")
+            .permission("https://api.example.com", "write:messages")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_remove_role_permissions() {
+    let mock = MockApi::new().await;
+    let role_id = "rol_0ABCDEF1234567890";
+    matcher_mgmt_roles_remove_permissions(&mock, role_id)
+        .and(matchers::body_json(json!({
+            "permissions": [
+                {
+                    "resource_server_identifier": "https://api.example.com",
+                    "permission_name": "write:messages",
+                },
+            ],
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    assert_ok!(
+        roles
+            .remove_permissions(role_id)
+            .permission("https://api.example.com", "write:messages")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_list_role_users_with_totals() {
+    let mock = MockApi::new().await;
+    let role_id = "rol_0ABCDEF1234567890";
+    let users = mgmt_users();
+    matcher_mgmt_roles_users(&mock, role_id)
+        .and(matchers::query_param("include_totals", "true"))
+        .respond_with(response_mgmt_paged("users", &users, 0, 14))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    let response = assert_ok!(roles.users(role_id).include_totals(true).send().await);
+    assert_eq!(response.users.len(), users.len());
+    assert_some_eq!(response.total, 14);
+}
+
+#[tokio::test]
+async fn should_assign_role_users() {
+    let mock = MockApi::new().await;
+    let role_id = "rol_0ABCDEF1234567890";
+    matcher_mgmt_roles_assign_users(&mock, role_id)
+        .and(matchers::body_json(json!({
+            "users": ["auth0|xxxyyyzz", "auth0|zzyyyxxx"],
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let roles = mgmt.roles();
+
+    assert_ok!(
+        roles
+            .assign_users(role_id)
+            .users(["auth0|xxxyyyzz", "auth0|zzyyyxxx"])
+            .send()
+            .await
+    );
+}
@@ -1,6 +1,8 @@
-use auth0_sdk::ManagementApi;
+use auth0_sdk::{error::ErrorCode, ManagementApi};
 use claym::*;
-use wiremock::matchers;
+use futures::TryStreamExt;
+use serde_json::json;
+use wiremock::{matchers, ResponseTemplate};
 
 use crate::mock::*;
 
@@ -138,7 +140,7 @@ async fn should_list_users_with_fields() {
 async fn should_get_user() {
     let mock = MockApi::new().await;
     let user_id = "auth0|xxxyyyzz";
-    matcher_mgmt_users_get(&mock, user_id)
+    matcher_mgmt_users_get(&mock, "auth0%7Cxxxyyyzz")
         .respond_with(response_mgmt_user())
         .mount(&mock)
         .await;
@@ -153,7 +155,7 @@ async fn should_get_user() {
 async fn should_get_user_with_fields_given_separately() {
     let mock = MockApi::new().await;
     let user_id = "auth0|xxxyyyzz";
-    matcher_mgmt_users_get(&mock, user_id)
+    matcher_mgmt_users_get(&mock, "auth0%7Cxxxyyyzz")
         .and(matchers::query_param("fields", "some,random,fields"))
         .respond_with(response_mgmt_user())
         .mount(&mock)
@@ -177,7 +179,7 @@ async fn should_get_user_with_fields_given_separately() {
 async fn should_get_user_with_fields() {
     let mock = MockApi::new().await;
     let user_id = "auth0|xxxyyyzz";
-    matcher_mgmt_users_get(&mock, user_id)
+    matcher_mgmt_users_get(&mock, "auth0%7Cxxxyyyzz")
         .and(matchers::query_param("fields", "some,random,fields"))
         .respond_with(response_mgmt_user())
         .mount(&mock)
@@ -194,3 +196,465 @@ async fn should_get_user_with_fields() {
             .await
     );
 }
+
+#[tokio::test(start_paused = true)]
+async fn fail_to_list_users_when_rate_limited() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(
+            response_mgmt_error(429, "too_many_requests", "Global limit has been reached")
+                .insert_header("x-ratelimit-remaining", "0"),
+        )
+        .expect(3)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let error = assert_err!(users.list().send().await);
+    assert_some_eq!(error.code(), &ErrorCode::TooManyRequests);
+    let error = assert_some!(error.api_error());
+    assert_eq!(error.status, 429);
+    assert_some_eq!(error.payload.status_code, 429);
+    assert_some!(error.headers.get("x-ratelimit-remaining"));
+}
+
+#[tokio::test]
+async fn fail_to_get_missing_user() {
+    let mock = MockApi::new().await;
+    let user_id = "auth0|xxxyyyzz";
+    matcher_mgmt_users_get(&mock, "auth0%7Cxxxyyyzz")
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "statusCode": 404,
+            "error": "Not Found",
+            "message": "The user does not exist.",
+        })))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let error = assert_err!(users.get(user_id).send().await);
+    assert_some_eq!(error.code(), &ErrorCode::NotFound);
+}
+
+#[tokio::test]
+async fn should_stream_users() {
+    let mock = MockApi::new().await;
+    let users = mgmt_users();
+    matcher_mgmt_users_list(&mock)
+        .and(matchers::query_param("page", "0"))
+        .and(matchers::query_param("per_page", "2"))
+        .and(matchers::query_param("include_totals", "true"))
+        .respond_with(response_mgmt_paged("users", &users, 0, 3))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_users_list(&mock)
+        .and(matchers::query_param("page", "1"))
+        .and(matchers::query_param("per_page", "2"))
+        .and(matchers::query_param("include_totals", "true"))
+        .respond_with(response_mgmt_paged("users", &users[..1], 2, 3))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let mut stream = users.list().per_page(2).into_stream();
+    assert_none!(stream.total());
+    assert_some!(assert_ok!(stream.try_next().await));
+    assert_some_eq!(stream.total(), 3);
+    let rest: Vec<_> = assert_ok!(stream.try_collect().await);
+    assert_eq!(rest.len(), 2);
+}
+
+#[tokio::test]
+async fn should_stop_streaming_users_at_search_limit() {
+    let mock = MockApi::new().await;
+    let users = mgmt_users();
+    matcher_mgmt_users_list(&mock)
+        .and(matchers::query_param("page", "499"))
+        .respond_with(response_mgmt_paged("users", &users, 998, 5000))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let stream = users.list().page(499).per_page(2).into_stream();
+    let users: Vec<_> = assert_ok!(stream.try_collect().await);
+    assert_eq!(users.len(), 2);
+}
+
+#[tokio::test]
+async fn should_stop_streaming_users_before_exceeding_search_limit() {
+    let mock = MockApi::new().await;
+    let users: Vec<_> = mgmt_users().into_iter().cycle().take(300).collect();
+    for page in 0..3 {
+        matcher_mgmt_users_list(&mock)
+            .and(matchers::query_param("page", page.to_string()))
+            .and(matchers::query_param("per_page", "300"))
+            .respond_with(response_mgmt_paged("users", &users, page * 300, 5000))
+            .expect(1)
+            .mount(&mock)
+            .await;
+    }
+    matcher_mgmt_users_list(&mock)
+        .and(matchers::query_param("page", "3"))
+        .respond_with(response_mgmt_error(
+            400,
+            "invalid_query_string",
+            "You can only page through the first 1000 records.",
+        ))
+        .expect(0)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let stream = users.list().per_page(300).into_stream();
+    let users: Vec<_> = assert_ok!(stream.try_collect().await);
+    assert_eq!(users.len(), 900);
+}
+
+#[tokio::test(start_paused = true)]
+async fn fail_to_stream_users() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_users_list(&mock)
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let mut stream = users.list().into_stream();
+    assert_err!(stream.try_next().await);
+    assert_none!(assert_ok!(stream.try_next().await));
+}
+
+#[tokio::test]
+async fn should_create_user() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_users_create(&mock)
+        .and(matchers::body_json(json!({
+            "connection": "Username-Password-Authentication",
+            "email": "john.doe@gmail.com",
+            "password": "~~password~~",
+            "user_metadata": { "theme": "dark" },
+            "verify_email": false,
+        })))
+        .respond_with(response_mgmt_user())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let user = assert_ok!(
+        users
+            .create("Username-Password-Authentication")
+            .email("john.doe@gmail.com")
+            .password("~~password~~")
+            .user_metadata(json!({ "theme": "dark" }))
+            .verify_email(false)
+            .send()
+            .await
+    );
+    assert_some!(user.user_id);
+}
+
+#[tokio::test]
+async fn should_update_user() {
+    let mock = MockApi::new().await;
+    let user_id = "auth0|xxxyyyzz";
+    matcher_mgmt_users_update(&mock, "auth0%7Cxxxyyyzz")
+        .and(matchers::body_json(json!({
+            "blocked": true,
+            "app_metadata": { "plan": null },
+        })))
+        .respond_with(response_mgmt_user())
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    assert_ok!(
+        users
+            .update(user_id)
+            .blocked(true)
+            .app_metadata(json!({ "plan": null }))
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_delete_user() {
+    let mock = MockApi::new().await;
+    let user_id = "auth0|xxxyyyzz";
+    matcher_mgmt_users_delete(&mock, "auth0%7Cxxxyyyzz")
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    assert_ok!(users.delete(user_id).send().await);
+}
+
+#[tokio::test]
+async fn fail_to_create_existing_user() {
+    let mock = MockApi::new().await;
+    matcher_mgmt_users_create(&mock)
+        .respond_with(response_mgmt_error(
+            409,
+            "user_exists",
+            "The user already exists.",
+        ))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let error = assert_err!(
+        users
+            .create("Username-Password-Authentication")
+            .email("john.doe@gmail.com")
+            .send()
+            .await
+    );
+    assert_some_eq!(error.code(), &ErrorCode::Other("user_exists".into()));
+}
+
+#[tokio::test]
+async fn should_list_user_roles_with_totals() {
+    let mock = MockApi::new().await;
+    let user_id = "auth0|xxxyyyzz";
+    let roles = mgmt_roles();
+    matcher_mgmt_users_roles(&mock, "auth0%7Cxxxyyyzz")
+        .and(matchers::query_param("include_totals", "true"))
+        .respond_with(response_mgmt_paged("roles", &roles, 0, 2))
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let response = assert_ok!(users.roles(user_id).include_totals(true).send().await);
+    assert_eq!(response.roles.len(), 2);
+    assert_eq!(response.roles[1].name, "Viewer");
+    assert_some_eq!(response.total, 2);
+}
+
+#[tokio::test]
+async fn should_stream_user_roles() {
+    let mock = MockApi::new().await;
+    let user_id = "auth0|xxxyyyzz";
+    let roles = mgmt_roles();
+    matcher_mgmt_users_roles(&mock, "auth0%7Cxxxyyyzz")
+        .and(matchers::query_param("page", "0"))
+        .and(matchers::query_param("per_page", "1"))
+        .respond_with(response_mgmt_paged("roles", &roles[..1], 0, 2))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_users_roles(&mock, "auth0%7Cxxxyyyzz")
+        .and(matchers::query_param("page", "1"))
+        .and(matchers::query_param("per_page", "1"))
+        .respond_with(response_mgmt_paged("roles", &roles[1..], 1, 2))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let roles: Vec<_> = assert_ok!(
+        users
+            .roles(user_id)
+            .per_page(1)
+            .into_stream()
+            .try_collect()
+            .await
+    );
+    assert_eq!(roles.len(), 2);
+}
+
+#[tokio::test]
+async fn should_assign_user_roles() {
+    let mock = MockApi::new().await;
+    let user_id = "auth0|xxxyyyzz";
+    matcher_mgmt_users_assign_roles(&mock, "auth0%7Cxxxyyyzz")
+        .and(matchers::body_json(json!({
+            "roles": ["rol_0ABCDEF1234567890", "rol_1BCDEFG2345678901"],
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    assert_ok!(
+        users
+            .assign_roles(user_id)
+            .role("rol_0ABCDEF1234567890")
+            .role("rol_1BCDEFG2345678901")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_remove_user_roles() {
+    let mock = MockApi::new().await;
+    let user_id = "auth0|xxxyyyzz";
+    matcher_mgmt_users_remove_roles(&mock, "auth0%7Cxxxyyyzz")
+        .and(matchers::body_json(json!({
+            "roles": ["rol_1BCDEFG2345678901"],
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    assert_ok!(
+        users
+            .remove_roles(user_id)
+            .roles(["rol_1BCDEFG2345678901"])
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_encode_user_id_in_sub_resource_paths() {
+    let mock = MockApi::new().await;
+    let user_id = "oauth2|custom/xxxyyyzz";
+    matcher_mgmt_users_assign_roles(&mock, "oauth2%7Ccustom%2Fxxxyyyzz")
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+    matcher_mgmt_users_remove_permissions(&mock, "oauth2%7Ccustom%2Fxxxyyyzz")
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    assert_ok!(
+        users
+            .assign_roles(user_id)
+            .role("rol_0ABCDEF1234567890")
+            .send()
+            .await
+    );
+    assert_ok!(
+        users
+            .remove_permissions(user_id)
+            .permission("https://api.example.com", "read:messages")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_list_user_permissions() {
+    let mock = MockApi::new().await;
+    let user_id = "auth0|xxxyyyzz";
+    matcher_mgmt_users_permissions(&mock, "auth0%7Cxxxyyyzz")
+        .respond_with(response_mgmt_user_permissions_list())
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    let response = assert_ok!(users.permissions(user_id).send().await);
+    assert_eq!(response.permissions.len(), 1);
+    let sources = &response.permissions[0].sources;
+    assert_eq!(sources.len(), 2);
+    assert_eq!(sources[0].source_type, "DIRECT");
+    assert_some_eq!(&sources[1].source_name, "Viewer");
+}
+
+#[tokio::test]
+async fn should_assign_user_permissions() {
+    let mock = MockApi::new().await;
+    let user_id = "auth0|xxxyyyzz";
+    matcher_mgmt_users_assign_permissions(&mock, "auth0%7Cxxxyyyzz")
+        .and(matchers::body_json(json!({
+            "permissions": [
+                {
+                    "resource_server_identifier": "https://api.example.com",
+                    "permission_name": "read:messages",
+                },
+            ],
+        })))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    assert_ok!(
+        users
+            .assign_permissions(user_id)
+            .permission("https://api.example.com", "read:messages")
+            .send()
+            .await
+    );
+}
+
+#[tokio::test]
+async fn should_remove_user_permissions() {
+    let mock = MockApi::new().await;
+    let user_id = "auth0|xxxyyyzz";
+    matcher_mgmt_users_remove_permissions(&mock, "auth0%7Cxxxyyyzz")
+        .and(matchers::body_json(json!({
+            "permissions": [
+                {
+                    "resource_server_identifier": "https://api.example.com",
+                    "permission_name": "read:messages",
+                },
+            ],
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock)
+        .await;
+
+    let mgmt = assert_ok!(ManagementApi::new(&mock.domain(), mock.api_token()));
+    let users = mgmt.users();
+
+    assert_ok!(
+        users
+            .remove_permissions(user_id)
+            .permission("https://api.example.com", "read:messages")
+            .send()
+            .await
+    );
+}